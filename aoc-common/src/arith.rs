@@ -0,0 +1,261 @@
+//! Sums, products and conversions that can be checked for overflow.
+//!
+//! By default these are the plain operators, which wrap silently in release builds. With the
+//! `checked` feature every operation is checked instead, and the first one to overflow is
+//! recorded so [`run`](crate::run) can report it as an error naming the day and operation:
+//!
+//! ```text
+//! cargo run --release -p aoc --features checked -- run --day 18
+//! ```
+
+use crate::{Solution, SolveError};
+use std::{
+    convert::TryFrom,
+    error::Error,
+    fmt::{self, Display},
+    ops::{Add, Mul},
+};
+
+/// An integer type the [`Checked`] operations work on.
+pub trait Integer: Copy + Add<Output = Self> + Mul<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn wrapping_add(self, other: Self) -> Self;
+    fn wrapping_mul(self, other: Self) -> Self;
+    /// The value as an `i128`, wrapping like `as`.
+    fn to_i128(self) -> i128;
+    /// The value of an `i128` truncated to this type, like `as`.
+    fn from_i128(value: i128) -> Self;
+}
+
+macro_rules! integer {
+    ($($t:ty),*) => {$(
+        impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$t>::checked_mul(self, other)
+            }
+
+            fn wrapping_add(self, other: Self) -> Self {
+                <$t>::wrapping_add(self, other)
+            }
+
+            fn wrapping_mul(self, other: Self) -> Self {
+                <$t>::wrapping_mul(self, other)
+            }
+
+            fn to_i128(self) -> i128 {
+                self as i128
+            }
+
+            fn from_i128(value: i128) -> Self {
+                value as $t
+            }
+        }
+    )*};
+}
+
+integer!(u32, u64, usize, i64);
+
+/// An operation that overflowed, and the day it was part of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow {
+    day: u8,
+    operation: &'static str,
+}
+
+impl Overflow {
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    pub fn operation(&self) -> &'static str {
+        self.operation
+    }
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {}: overflow in {}", self.day, self.operation)
+    }
+}
+
+impl Error for Overflow {}
+
+/// The arithmetic for one operation of a day's solution, named so an overflow can say where it
+/// happened. See the [module docs](self).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Checked {
+    day: u8,
+    operation: &'static str,
+}
+
+impl Checked {
+    /// Arithmetic for `operation` in `S`, e.g. `Checked::new::<Day1>("the expense product")`.
+    pub fn new<S: Solution>(operation: &'static str) -> Checked {
+        Checked {
+            day: S::DAY,
+            operation,
+        }
+    }
+
+    pub fn add<T: Integer>(self, a: T, b: T) -> T {
+        if cfg!(feature = "checked") {
+            a.checked_add(b)
+                .unwrap_or_else(|| self.overflowed(a.wrapping_add(b)))
+        } else {
+            a + b
+        }
+    }
+
+    pub fn mul<T: Integer>(self, a: T, b: T) -> T {
+        if cfg!(feature = "checked") {
+            a.checked_mul(b)
+                .unwrap_or_else(|| self.overflowed(a.wrapping_mul(b)))
+        } else {
+            a * b
+        }
+    }
+
+    pub fn sum<T: Integer>(self, values: impl IntoIterator<Item = T>) -> T {
+        values.into_iter().fold(T::ZERO, |a, b| self.add(a, b))
+    }
+
+    pub fn product<T: Integer>(self, values: impl IntoIterator<Item = T>) -> T {
+        values.into_iter().fold(T::ONE, |a, b| self.mul(a, b))
+    }
+
+    /// Converts between integer types, which is an overflow if `value` doesn't fit in `U`.
+    pub fn convert<T: Integer, U: Integer + TryFrom<T>>(self, value: T) -> U {
+        let truncated = U::from_i128(value.to_i128());
+        if cfg!(feature = "checked") {
+            U::try_from(value).unwrap_or_else(|_| self.overflowed(truncated))
+        } else {
+            truncated
+        }
+    }
+
+    fn overflowed<T>(self, wrapped: T) -> T {
+        record(Overflow {
+            day: self.day,
+            operation: self.operation,
+        });
+        wrapped
+    }
+}
+
+#[cfg(feature = "checked")]
+thread_local! {
+    static FIRST: std::cell::Cell<Option<Overflow>> = const { std::cell::Cell::new(None) };
+}
+
+#[cfg(feature = "checked")]
+fn record(overflow: Overflow) {
+    FIRST.with(|first| {
+        if first.get().is_none() {
+            first.set(Some(overflow));
+        }
+    });
+}
+
+#[cfg(not(feature = "checked"))]
+fn record(_: Overflow) {}
+
+/// The first operation to overflow on this thread since the last call, which is never anything
+/// without the `checked` feature.
+pub fn take_overflow() -> Option<Overflow> {
+    #[cfg(feature = "checked")]
+    return FIRST.with(|first| first.take());
+    #[cfg(not(feature = "checked"))]
+    None
+}
+
+/// Runs `f`, failing with the first overflow it caused.
+pub(crate) fn checked<T>(f: impl FnOnce() -> T) -> Result<T, SolveError> {
+    take_overflow();
+    let value = f();
+    match take_overflow() {
+        Some(overflow) => Err(SolveError::Overflow(overflow)),
+        None => Ok(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{take_overflow, Checked};
+    use crate::{config::NoParams, ParseError, Solution, SolveError};
+
+    struct Sums;
+
+    impl Solution for Sums {
+        const DAY: u8 = 7;
+
+        type Params = NoParams;
+        type Parsed = Vec<u32>;
+        type Answer1 = u32;
+        type Answer2 = usize;
+
+        fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+            crate::parse::numbers(input)
+        }
+
+        fn part1(parsed: &Self::Parsed, _: &Self::Params) -> Result<u32, SolveError> {
+            Ok(Checked::new::<Sums>("the total").sum(parsed.iter().copied()))
+        }
+
+        fn part2(parsed: &Self::Parsed, _: &Self::Params) -> Result<usize, SolveError> {
+            Ok(parsed.len())
+        }
+    }
+
+    #[test]
+    fn should_do_arithmetic_that_fits() {
+        let op = Checked::new::<Sums>("the totals");
+
+        assert_eq!(op.sum(vec![1_u32, 2, 3]), 6);
+        assert_eq!(op.product(vec![2_u64, 3, 4]), 24);
+        assert_eq!(op.product(Vec::<usize>::new()), 1);
+        assert_eq!(op.convert::<i64, usize>(42), 42);
+        assert_eq!(take_overflow(), None);
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    fn should_report_the_first_overflow() {
+        let sum = Checked::new::<Sums>("the sum");
+        let product = Checked::new::<Sums>("the product");
+
+        assert_eq!(sum.add(u32::MAX, 2), 1);
+        product.mul(u32::MAX, 2);
+        let overflow = take_overflow().unwrap();
+
+        assert_eq!(overflow.to_string(), "day 7: overflow in the sum");
+        assert_eq!(take_overflow(), None);
+        product.convert::<i64, usize>(-1);
+        assert_eq!(take_overflow().unwrap().operation(), "the product");
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    fn should_fail_the_part_that_overflowed() {
+        use crate::{run, Part};
+
+        let reports = run::<Sums>(&Part::ALL, "4294967295\n1", &NoParams {});
+
+        assert_eq!(
+            reports[0].answer.as_ref().unwrap_err().to_string(),
+            "day 7: overflow in the total"
+        );
+        assert!(matches!(reports[0].answer, Err(SolveError::Overflow(_))));
+        assert_eq!(reports[1].answer.as_deref(), Ok("2"));
+    }
+}
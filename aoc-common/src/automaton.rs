@@ -0,0 +1,194 @@
+use crate::grid::{Grid2D, Pos};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// A cellular automaton that works out each generation from the one before it.
+pub trait Automaton {
+    /// Advances by a single generation, returning whether any cell changed.
+    fn step(&mut self) -> bool;
+
+    /// Advances by `generations` generations.
+    fn run(&mut self, generations: usize) {
+        for _ in 0..generations {
+            self.step();
+        }
+    }
+
+    /// Advances until a generation is the same as the one before it, returning how many
+    /// generations changed something. Never returns if the automaton doesn't settle.
+    fn run_until_stable(&mut self) -> usize {
+        let mut generations = 0;
+        while self.step() {
+            generations += 1;
+        }
+        generations
+    }
+}
+
+/// An automaton over a bounded grid where every cell has a state.
+///
+/// `neighbours` lists the positions whose states are handed to `rule` along with the
+/// cell's own state to decide its next state.
+pub struct Dense<T, N, R> {
+    grid: Grid2D<T>,
+    neighbours: N,
+    rule: R,
+}
+
+impl<T, N, R> Dense<T, N, R>
+where
+    N: Fn(&Grid2D<T>, Pos) -> Vec<Pos>,
+    R: Fn(&T, &[T]) -> T,
+{
+    pub fn new(grid: Grid2D<T>, neighbours: N, rule: R) -> Self {
+        Dense {
+            grid,
+            neighbours,
+            rule,
+        }
+    }
+}
+
+impl<T, N, R> Dense<T, N, R> {
+    pub fn grid(&self) -> &Grid2D<T> {
+        &self.grid
+    }
+}
+
+impl<T, N, R> Automaton for Dense<T, N, R>
+where
+    T: Clone + PartialEq,
+    N: Fn(&Grid2D<T>, Pos) -> Vec<Pos>,
+    R: Fn(&T, &[T]) -> T,
+{
+    fn step(&mut self) -> bool {
+        let grid = &self.grid;
+        let mut states = Vec::new();
+        let next = Grid2D::from_fn(grid.width(), grid.height(), |pos| {
+            states.clear();
+            states.extend(
+                (self.neighbours)(grid, pos)
+                    .into_iter()
+                    .map(|p| grid[p].clone()),
+            );
+            (self.rule)(&grid[pos], &states)
+        });
+
+        let changed = next != self.grid;
+        self.grid = next;
+        changed
+    }
+}
+
+/// An unbounded automaton where each cell is either live or dead, storing only the live
+/// cells.
+///
+/// `rule` gets whether a cell is live and how many of its `neighbours` are, and decides
+/// whether it's live in the next generation. Only cells with a live neighbour are
+/// considered for birth, so a dead cell with no live neighbours always stays dead.
+pub struct Sparse<P, N, R> {
+    live: HashSet<P>,
+    neighbours: N,
+    rule: R,
+}
+
+impl<P, N, R> Sparse<P, N, R>
+where
+    P: Eq + Hash,
+    N: Fn(&P) -> Vec<P>,
+    R: Fn(bool, usize) -> bool,
+{
+    pub fn new(live: impl IntoIterator<Item = P>, neighbours: N, rule: R) -> Self {
+        Sparse {
+            live: live.into_iter().collect(),
+            neighbours,
+            rule,
+        }
+    }
+}
+
+impl<P, N, R> Sparse<P, N, R> {
+    pub fn live(&self) -> &HashSet<P> {
+        &self.live
+    }
+}
+
+impl<P, N, R> Automaton for Sparse<P, N, R>
+where
+    P: Eq + Hash + Clone,
+    N: Fn(&P) -> Vec<P>,
+    R: Fn(bool, usize) -> bool,
+{
+    fn step(&mut self) -> bool {
+        let mut live_neighbours: HashMap<P, usize> = HashMap::new();
+        for cell in self.live.iter() {
+            for neighbour in (self.neighbours)(cell) {
+                *live_neighbours.entry(neighbour).or_insert(0) += 1;
+            }
+        }
+
+        let isolated = self
+            .live
+            .iter()
+            .filter(|cell| !live_neighbours.contains_key(cell) && (self.rule)(true, 0));
+        let next: HashSet<P> = live_neighbours
+            .iter()
+            .filter(|&(cell, &count)| (self.rule)(self.live.contains(cell), count))
+            .map(|(cell, _)| cell)
+            .chain(isolated)
+            .cloned()
+            .collect();
+
+        let changed = next != self.live;
+        self.live = next;
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Automaton, Dense, Sparse};
+    use crate::geometry::DIRECTIONS;
+    use crate::grid::{Adjacency, Grid2D};
+
+    fn life(live: bool, neighbours: usize) -> bool {
+        matches!((live, neighbours), (true, 2) | (_, 3))
+    }
+
+    #[test]
+    fn sparse_blinker_oscillates() {
+        let neighbours = |&(x, y): &(i64, i64)| -> Vec<(i64, i64)> {
+            DIRECTIONS.iter().map(|(dx, dy)| (x + dx, y + dy)).collect()
+        };
+        let mut blinker = Sparse::new(vec![(0, -1), (0, 0), (0, 1)], neighbours, life);
+
+        assert!(blinker.step());
+        let mut cells: Vec<_> = blinker.live().iter().copied().collect();
+        cells.sort_unstable();
+        assert_eq!(cells, vec![(-1, 0), (0, 0), (1, 0)]);
+
+        blinker.run(3);
+        assert!(blinker.live().contains(&(0, -1)));
+        assert_eq!(blinker.live().len(), 3);
+    }
+
+    #[test]
+    fn dense_runs_until_stable() {
+        let grid: Grid2D<char> = ".....\n.##..\n.#...\n.....".parse().unwrap();
+        let mut automaton = Dense::new(
+            grid,
+            |g, pos| g.neighbours(pos, Adjacency::All).collect(),
+            |&c, neighbours: &[char]| {
+                let live = neighbours.iter().filter(|&&n| n == '#').count();
+                if life(c == '#', live) {
+                    '#'
+                } else {
+                    '.'
+                }
+            },
+        );
+
+        assert_eq!(automaton.run_until_stable(), 1);
+        assert_eq!(automaton.grid().to_string(), ".....\n.##..\n.##..\n.....");
+    }
+}
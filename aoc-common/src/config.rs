@@ -0,0 +1,267 @@
+//! Puzzle parameters that aren't part of the input, such as how many rounds a game lasts.
+//!
+//! Each day reads its parameters from a `[dayN]` table in `aoc.toml`, and anything left out
+//! keeps the value from the puzzle:
+//!
+//! ```toml
+//! [day9]
+//! preamble = 5
+//!
+//! [day15]
+//! part2_turns = 1000
+//! ```
+
+use crate::Solution;
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt::{self, Display},
+    fs, io,
+    path::Path,
+};
+use toml::{Table, Value};
+
+/// The parameters of a day whose puzzle has nothing to tune.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NoParams {}
+
+/// Every day's parameters, see the [module docs](self).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Config {
+    days: BTreeMap<u8, Table>,
+}
+
+/// Why a config couldn't be read or doesn't suit a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigError(String);
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Error for ConfigError {}
+
+impl Config {
+    /// Parses the contents of an `aoc.toml`.
+    pub fn parse(text: &str) -> Result<Config, ConfigError> {
+        let table = text
+            .parse::<Table>()
+            .map_err(|e| ConfigError(e.message().to_owned()))?;
+
+        let mut config = Config::default();
+        for (key, value) in table {
+            match value {
+                Value::Table(params) => {
+                    config.days.insert(day_number(&key)?, params);
+                }
+                _ => return Err(ConfigError(format!("expected `[{}]` to be a table", key))),
+            }
+        }
+        Ok(config)
+    }
+
+    /// Reads the config at `path`, or uses the puzzles' own parameters if there's no such file.
+    pub fn load(path: &Path) -> Result<Config, ConfigError> {
+        match fs::read_to_string(path) {
+            Ok(text) => {
+                Config::parse(&text).map_err(|e| ConfigError(format!("{}: {}", path.display(), e)))
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(ConfigError(format!("{}: {}", path.display(), e))),
+        }
+    }
+
+    /// Overrides a parameter with an assignment like `day9.preamble=5`. The value is read as
+    /// TOML, or as a plain string if it isn't valid TOML, so `day7.bag=faded blue` works.
+    pub fn set(&mut self, assignment: &str) -> Result<(), ConfigError> {
+        let expected = || ConfigError(format!("expected `dayN.name=value`, not {:?}", assignment));
+        let (key, value) = assignment.split_once('=').ok_or_else(expected)?;
+        let (day, name) = key.trim().split_once('.').ok_or_else(expected)?;
+        let value = value.trim();
+
+        let value = format!("value = {}", value)
+            .parse::<Table>()
+            .ok()
+            .and_then(|mut t| t.remove("value"))
+            .unwrap_or_else(|| Value::String(value.to_owned()));
+        self.days
+            .entry(day_number(day)?)
+            .or_default()
+            .insert(name.to_owned(), value);
+        Ok(())
+    }
+
+    /// The parameters for `S`, keeping the puzzle's values for any that aren't set, as long as
+    /// [`S::check_params`](Solution::check_params) accepts them.
+    pub fn params<S: Solution>(&self) -> Result<S::Params, ConfigError> {
+        let invalid = |reason: &dyn Display| ConfigError(format!("[day{}]: {}", S::DAY, reason));
+        let params = match self.days.get(&S::DAY) {
+            None => S::Params::default(),
+            Some(params) => Value::Table(params.clone())
+                .try_into()
+                .map_err(|e: toml::de::Error| invalid(&e.message()))?,
+        };
+        S::check_params(&params).map_err(|e| invalid(&e))?;
+        Ok(params)
+    }
+}
+
+fn day_number(key: &str) -> Result<u8, ConfigError> {
+    key.strip_prefix("day")
+        .and_then(|n| n.parse().ok())
+        .filter(|n| (1..=25).contains(n))
+        .ok_or_else(|| {
+            ConfigError(format!(
+                "expected a day from `day1` to `day25`, not {:?}",
+                key
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Config, NoParams};
+    use crate::{ParseError, Solution, SolveError};
+    use serde::Deserialize;
+
+    struct Game;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(default, deny_unknown_fields)]
+    struct Rules {
+        rounds: usize,
+        start: String,
+    }
+
+    impl Default for Rules {
+        fn default() -> Self {
+            Rules {
+                rounds: 100,
+                start: String::from("shiny gold"),
+            }
+        }
+    }
+
+    impl Solution for Game {
+        const DAY: u8 = 3;
+
+        type Params = Rules;
+        type Parsed = ();
+        type Answer1 = usize;
+        type Answer2 = usize;
+
+        fn parse(_: &str) -> Result<Self::Parsed, ParseError> {
+            Ok(())
+        }
+
+        fn part1(_: &Self::Parsed, rules: &Rules) -> Result<usize, SolveError> {
+            Ok(rules.rounds)
+        }
+
+        fn part2(_: &Self::Parsed, _: &Rules) -> Result<usize, SolveError> {
+            Ok(0)
+        }
+
+        fn check_params(rules: &Rules) -> Result<(), String> {
+            match rules.rounds {
+                0 => Err(String::from("expected at least one round")),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    struct Plain;
+
+    impl Solution for Plain {
+        const DAY: u8 = 4;
+
+        type Params = NoParams;
+        type Parsed = ();
+        type Answer1 = usize;
+        type Answer2 = usize;
+
+        fn parse(_: &str) -> Result<Self::Parsed, ParseError> {
+            Ok(())
+        }
+
+        fn part1(_: &Self::Parsed, _: &Self::Params) -> Result<usize, SolveError> {
+            Ok(0)
+        }
+
+        fn part2(_: &Self::Parsed, _: &Self::Params) -> Result<usize, SolveError> {
+            Ok(0)
+        }
+    }
+
+    #[test]
+    fn should_default_what_isnt_set() {
+        let config = Config::parse("[day3]\nrounds = 10\n").unwrap();
+
+        assert_eq!(
+            config.params::<Game>().unwrap(),
+            Rules {
+                rounds: 10,
+                ..Rules::default()
+            }
+        );
+        assert_eq!(
+            Config::default().params::<Game>().unwrap(),
+            Rules::default()
+        );
+    }
+
+    #[test]
+    fn should_override_with_assignments() {
+        let mut config = Config::parse("[day3]\nrounds = 10\n").unwrap();
+        config.set("day3.rounds=20").unwrap();
+        config.set("day3.start = dark red").unwrap();
+
+        assert_eq!(
+            config.params::<Game>().unwrap(),
+            Rules {
+                rounds: 20,
+                start: String::from("dark red"),
+            }
+        );
+        assert!(config.set("rounds=20").is_err());
+        assert!(config.set("day26.rounds=20").is_err());
+    }
+
+    #[test]
+    fn should_reject_unknown_and_mistyped_params() {
+        let typo = Config::parse("[day3]\nround = 10\n").unwrap();
+        assert!(typo
+            .params::<Game>()
+            .unwrap_err()
+            .to_string()
+            .starts_with("[day3]: unknown field `round`"));
+
+        let mistyped = Config::parse("[day3]\nrounds = \"ten\"\n").unwrap();
+        assert!(mistyped.params::<Game>().is_err());
+
+        let extra = Config::parse("[day4]\nrounds = 10\n").unwrap();
+        assert!(extra.params::<Plain>().is_err());
+        assert_eq!(extra.params::<Game>().unwrap(), Rules::default());
+    }
+
+    #[test]
+    fn should_reject_params_the_day_rejects() {
+        let config = Config::parse("[day3]\nrounds = 0\n").unwrap();
+
+        assert_eq!(
+            config.params::<Game>().unwrap_err().to_string(),
+            "[day3]: expected at least one round"
+        );
+    }
+
+    #[test]
+    fn should_only_accept_day_tables() {
+        assert!(Config::parse("rounds = 10\n").is_err());
+        assert!(Config::parse("[day0]\n").is_err());
+        assert!(Config::parse("[day25]\n").is_ok());
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{arith::Overflow, progress::Timeout, Solution};
+use std::fmt::{self, Display};
+
+/// A malformed piece of puzzle input: which day it was for, where it sits in the input and what
+/// was wrong with it.
+///
+/// Parsers only need to say which slice of the input they choke on; the line and column are
+/// worked out from where that slice sits once the error reaches [`parse_input`](crate::parse_input).
+#[derive(Clone, Debug)]
+pub struct ParseError {
+    day: u8,
+    line: usize,
+    column: usize,
+    text: String,
+    reason: String,
+    // address of `text` in the input it was cut from, used to locate it later
+    addr: usize,
+}
+
+impl ParseError {
+    /// Blames `text`, which should be a slice of the input being parsed.
+    pub fn new(text: &str, reason: impl Display) -> ParseError {
+        ParseError {
+            day: 0,
+            line: 0,
+            column: 0,
+            text: text.to_owned(),
+            reason: reason.to_string(),
+            addr: text.as_ptr() as usize,
+        }
+    }
+
+    /// Fills in the day and, if the blamed text was cut from `input`, its line and column.
+    pub fn locate(mut self, day: u8, input: &str) -> ParseError {
+        self.day = day;
+
+        let start = input.as_ptr() as usize;
+        if (start..=start + input.len()).contains(&self.addr) {
+            let before = &input[..self.addr - start];
+            let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+            self.line = before.matches('\n').count() + 1;
+            self.column = before[line_start..].chars().count() + 1;
+        }
+
+        self
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// 1-based line of the offending text, or 0 if it couldn't be located.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// 1-based column of the offending text, or 0 if it couldn't be located.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+// two errors are the same when they say the same thing, wherever their text was cut from
+impl PartialEq for ParseError {
+    fn eq(&self, other: &ParseError) -> bool {
+        self.day == other.day
+            && self.line == other.line
+            && self.column == other.column
+            && self.text == other.text
+            && self.reason == other.reason
+    }
+}
+
+impl Eq for ParseError {}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {}", self.day)?;
+        if self.line > 0 {
+            write!(f, ", line {}, column {}", self.line, self.column)?;
+        }
+        write!(f, ": {} (at {:?})", self.reason, self.text)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Well-formed input a part has no answer for, like expenses where no two add up to 2020.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Unsolvable {
+    day: u8,
+    reason: String,
+}
+
+impl Unsolvable {
+    /// Why `S` can't answer, e.g. `Unsolvable::new::<Day1>("no two expenses sum to 2020")`.
+    pub fn new<S: Solution>(reason: impl Display) -> Unsolvable {
+        Unsolvable {
+            day: S::DAY,
+            reason: reason.to_string(),
+        }
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl Display for Unsolvable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {}: {}", self.day, self.reason)
+    }
+}
+
+impl std::error::Error for Unsolvable {}
+
+/// Why a part has no answer: its input didn't parse, the puzzle can't be solved for it, it ran
+/// out of time, or with the `checked` feature, something overflowed working it out.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SolveError {
+    Parse(ParseError),
+    Unsolvable(Unsolvable),
+    Overflow(Overflow),
+    Timeout(Timeout),
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Parse(e) => e.fmt(f),
+            SolveError::Unsolvable(e) => e.fmt(f),
+            SolveError::Overflow(e) => e.fmt(f),
+            SolveError::Timeout(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+impl From<ParseError> for SolveError {
+    fn from(e: ParseError) -> SolveError {
+        SolveError::Parse(e)
+    }
+}
+
+impl From<Unsolvable> for SolveError {
+    fn from(e: Unsolvable) -> SolveError {
+        SolveError::Unsolvable(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseError;
+
+    #[test]
+    fn should_locate_text_in_input() {
+        let input = "1-3 a: abcde\n1-3 b: cdefg\n2-9 c? ccccccccc";
+        let at = input.find('?').unwrap();
+        let err = ParseError::new(&input[at..=at], "expected `:`").locate(2, input);
+
+        assert_eq!((err.day(), err.line(), err.column()), (2, 3, 6));
+        assert_eq!(
+            err.to_string(),
+            "day 2, line 3, column 6: expected `:` (at \"?\")"
+        );
+    }
+
+    #[test]
+    fn should_locate_text_at_end_of_input() {
+        let input = "abc\n";
+        let err = ParseError::new(&input[4..], "missing line").locate(1, input);
+
+        assert_eq!((err.line(), err.column()), (2, 1));
+    }
+
+    #[test]
+    fn should_equal_the_same_error_cut_from_elsewhere() {
+        let (input, copy) = ("1\nx\n", String::from("1\nx\n"));
+        let err = |input: &str| ParseError::new(&input[2..3], "bad").locate(1, input);
+
+        assert_eq!(err(input), err(&copy));
+    }
+
+    #[test]
+    fn should_leave_foreign_text_unlocated() {
+        let elsewhere = String::from("elsewhere");
+        let err = ParseError::new(&elsewhere, "bad").locate(7, "input");
+
+        assert_eq!(err.to_string(), "day 7: bad (at \"elsewhere\")");
+    }
+}
@@ -0,0 +1,168 @@
+//! Traces of the steps a solution took to its answer, for working out why an answer is wrong.
+//!
+//! A solver makes an [`Explain`] for each step it wants to show and notes what it found along
+//! the way. Each note is a line naming the day, the stage of [`run`](crate::run) and the step:
+//!
+//! ```text
+//! day 7, part 2, counting bags: shiny gold holds 126 bags
+//! ```
+//!
+//! Notes go nowhere unless the thread was given somewhere to write them with [`explain_to`], and
+//! until then the closures describing them aren't even called, so a note costs one thread-local
+//! lookup.
+
+use crate::{Part, Solution};
+use std::{
+    cell::{Cell, RefCell},
+    io::Write,
+};
+
+thread_local! {
+    static ON: Cell<bool> = const { Cell::new(false) };
+    static OUT: RefCell<Option<Box<dyn Write>>> = RefCell::new(None);
+    static STAGE: Cell<Option<Part>> = const { Cell::new(None) };
+}
+
+/// Sets where notes on this thread are written from now on, or stops writing them.
+pub fn explain_to(out: Option<Box<dyn Write>>) {
+    ON.with(|on| on.set(out.is_some()));
+    OUT.with(|o| *o.borrow_mut() = out);
+}
+
+/// Runs `f` as the given part, or as parsing, so its notes say which it was.
+pub(crate) fn explaining<T>(part: Option<Part>, f: impl FnOnce() -> T) -> T {
+    let outer = STAGE.with(|s| s.replace(part));
+    let value = f();
+    STAGE.with(|s| s.set(outer));
+    value
+}
+
+/// One step of a day's solution that can say what it found, see the [module docs](self).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Explain {
+    day: u8,
+    step: &'static str,
+}
+
+impl Explain {
+    /// A step of `S`, e.g. `Explain::new::<Day7>("counting bags")`.
+    pub fn new<S: Solution>(step: &'static str) -> Explain {
+        Explain { day: S::DAY, step }
+    }
+
+    /// Whether notes are being written, for steps that need extra work to describe.
+    #[inline]
+    pub fn is_on(&self) -> bool {
+        ON.with(Cell::get)
+    }
+
+    /// Writes the note `detail` describes, if notes are being written.
+    #[inline]
+    pub fn note(&self, detail: impl FnOnce() -> String) {
+        if self.is_on() {
+            self.write(&detail());
+        }
+    }
+
+    fn write(&self, detail: &str) {
+        let stage = match STAGE.with(Cell::get) {
+            Some(part) => format!("part {}", part),
+            None => String::from("parsing"),
+        };
+        OUT.with(|out| {
+            if let Some(out) = out.borrow_mut().as_mut() {
+                // a trace that can't be written shouldn't stop the solve
+                let _ = writeln!(
+                    out,
+                    "day {}, {}, {}: {}",
+                    self.day, stage, self.step, detail
+                );
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{explain_to, Explain};
+    use crate::{config::NoParams, run, ParseError, Part, Solution, SolveError};
+    use std::{cell::RefCell, io, rc::Rc};
+
+    /// A writer whose output can be read back after it's been handed over.
+    #[derive(Clone, Default)]
+    struct Shared(Rc<RefCell<Vec<u8>>>);
+
+    impl io::Write for Shared {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Shared {
+        fn text(&self) -> String {
+            String::from_utf8(self.0.borrow().clone()).unwrap()
+        }
+    }
+
+    struct Evens;
+
+    impl Solution for Evens {
+        const DAY: u8 = 9;
+
+        type Params = NoParams;
+        type Parsed = Vec<u32>;
+        type Answer1 = usize;
+        type Answer2 = u32;
+
+        fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+            let numbers = crate::parse::numbers(input)?;
+            Explain::new::<Evens>("reading").note(|| format!("{} numbers", numbers.len()));
+            Ok(numbers)
+        }
+
+        fn part1(numbers: &Self::Parsed, _: &Self::Params) -> Result<usize, SolveError> {
+            let explain = Explain::new::<Evens>("counting evens");
+            Ok(numbers
+                .iter()
+                .filter(|&&n| {
+                    let even = n % 2 == 0;
+                    explain.note(|| format!("{} is {}", n, if even { "even" } else { "odd" }));
+                    even
+                })
+                .count())
+        }
+
+        fn part2(numbers: &Self::Parsed, _: &Self::Params) -> Result<u32, SolveError> {
+            Ok(numbers.iter().sum())
+        }
+    }
+
+    #[test]
+    fn should_stay_quiet_by_default() {
+        let explain = Explain::new::<Evens>("counting evens");
+
+        assert!(!explain.is_on());
+        explain.note(|| panic!("notes shouldn't be described when nobody's listening"));
+    }
+
+    #[test]
+    fn should_note_each_step_with_its_stage() {
+        let out = Shared::default();
+        explain_to(Some(Box::new(out.clone())));
+        let reports = run::<Evens>(&Part::ALL, "3\n4\n", &NoParams {});
+        explain_to(None);
+
+        assert_eq!(reports[0].answer.as_deref(), Ok("1"));
+        assert_eq!(
+            out.text(),
+            "day 9, parsing, reading: 2 numbers\n\
+             day 9, part 1, counting evens: 3 is odd\n\
+             day 9, part 1, counting evens: 4 is even\n"
+        );
+        assert!(!Explain::new::<Evens>("counting evens").is_on());
+    }
+}
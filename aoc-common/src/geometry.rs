@@ -0,0 +1,330 @@
+//! Positions and directions on square grids, offsets around a cell in any number of dimensions,
+//! and hexagons on hexagonal grids.
+//!
+//! Everything is integers, so turning is exact: a [`Vec2`] only ever turns by whole quarters,
+//! and [`Heading`] and [`Vec2`] both take `y` as growing northwards.
+
+use crate::ParseError;
+use std::{
+    ops::{Add, AddAssign, Mul, Neg, Sub},
+    str::FromStr,
+};
+
+/// Offsets of the four cells sharing an edge with a cell on a square grid.
+pub const ORTHOGONAL: [(i64, i64); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of the eight cells surrounding a cell on a square grid.
+pub const DIRECTIONS: [(i64, i64); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// Offsets of every cell touching a cell in `N` dimensions, diagonals included, i.e. each
+/// coordinate moving by -1, 0 or 1 but not all of them 0.
+pub fn around<const N: usize>() -> Vec<[i64; N]> {
+    let cube = 3_usize.pow(N as u32);
+    (0..cube)
+        .filter(|&i| i != cube / 2)
+        .map(|i| {
+            let mut offset = [0; N];
+            let mut rest = i;
+            for axis in offset.iter_mut() {
+                *axis = (rest % 3) as i64 - 1;
+                rest /= 3;
+            }
+            offset
+        })
+        .collect()
+}
+
+/// A position or offset on a plane.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Vec2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2 { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Vec2 {
+        Vec2 { x, y }
+    }
+
+    /// Turns `quarters` quarter turns anticlockwise around the origin.
+    pub fn rotate_left(self, quarters: i64) -> Vec2 {
+        match quarters.rem_euclid(4) {
+            0 => self,
+            1 => Vec2::new(-self.y, self.x),
+            2 => -self,
+            _ => Vec2::new(self.y, -self.x),
+        }
+    }
+
+    /// Turns `quarters` quarter turns clockwise around the origin.
+    pub fn rotate_right(self, quarters: i64) -> Vec2 {
+        self.rotate_left(-quarters)
+    }
+
+    /// The distance from the origin moving only along the axes.
+    pub fn manhattan(self) -> i64 {
+        self.x.abs() + self.y.abs()
+    }
+}
+
+macro_rules! vector_ops {
+    ($t:ident { $($axis:ident),* }) => {
+        impl Add for $t {
+            type Output = $t;
+
+            fn add(self, other: $t) -> $t {
+                $t { $($axis: self.$axis + other.$axis),* }
+            }
+        }
+
+        impl AddAssign for $t {
+            fn add_assign(&mut self, other: $t) {
+                *self = *self + other;
+            }
+        }
+
+        impl Sub for $t {
+            type Output = $t;
+
+            fn sub(self, other: $t) -> $t {
+                $t { $($axis: self.$axis - other.$axis),* }
+            }
+        }
+
+        impl Neg for $t {
+            type Output = $t;
+
+            fn neg(self) -> $t {
+                $t { $($axis: -self.$axis),* }
+            }
+        }
+
+        impl Mul<i64> for $t {
+            type Output = $t;
+
+            fn mul(self, scale: i64) -> $t {
+                $t { $($axis: self.$axis * scale),* }
+            }
+        }
+    };
+}
+
+vector_ops!(Vec2 { x, y });
+
+/// A compass direction, written as its initial.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Heading {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Heading {
+    /// Clockwise from north.
+    pub const ALL: [Heading; 4] = [Heading::North, Heading::East, Heading::South, Heading::West];
+
+    /// The heading a letter from `NESW` stands for.
+    pub fn from_char(c: char) -> Option<Heading> {
+        match c {
+            'N' => Some(Heading::North),
+            'E' => Some(Heading::East),
+            'S' => Some(Heading::South),
+            'W' => Some(Heading::West),
+            _ => None,
+        }
+    }
+
+    /// One step this way.
+    pub fn unit(self) -> Vec2 {
+        match self {
+            Heading::North => Vec2::new(0, 1),
+            Heading::East => Vec2::new(1, 0),
+            Heading::South => Vec2::new(0, -1),
+            Heading::West => Vec2::new(-1, 0),
+        }
+    }
+}
+
+impl FromStr for Heading {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next().and_then(Heading::from_char), chars.next()) {
+            (Some(heading), None) => Ok(heading),
+            _ => Err(ParseError::new(s, "expected `N`, `E`, `S` or `W`")),
+        }
+    }
+}
+
+/// A hexagon on a grid of them, in cube coordinates that always sum to zero.
+///
+/// The hexagons have points at the top and bottom, so rows run east to west: moving east adds
+/// one to `x` and takes one from `y`, and moving south east adds one to `z` and takes one from
+/// `y`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Hex {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl Hex {
+    pub const ORIGIN: Hex = Hex { x: 0, y: 0, z: 0 };
+
+    /// The hexagon at axial coordinates `(q, r)`, i.e. `q` steps east and then `r` south east.
+    pub const fn from_axial(q: i32, r: i32) -> Hex {
+        Hex {
+            x: q,
+            y: -q - r,
+            z: r,
+        }
+    }
+
+    /// The axial coordinates `(q, r)` of this hexagon, see [`from_axial`](Hex::from_axial).
+    pub fn axial(self) -> (i32, i32) {
+        (self.x, self.z)
+    }
+
+    /// The hexagon next to this one in direction `dir`.
+    pub fn step(self, dir: HexDirection) -> Hex {
+        let (dx, dy, dz) = dir.offset();
+        Hex {
+            x: self.x + dx,
+            y: self.y + dy,
+            z: self.z + dz,
+        }
+    }
+
+    /// The six hexagons next to this one, in the order of [`HexDirection::ALL`].
+    pub fn neighbours(self) -> [Hex; 6] {
+        HexDirection::ALL.map(|dir| self.step(dir))
+    }
+
+    /// The fewest steps from this hexagon to `other`.
+    pub fn distance(self, other: Hex) -> i32 {
+        let (dx, dy, dz) = (self.x - other.x, self.y - other.y, self.z - other.z);
+        dx.abs().max(dy.abs()).max(dz.abs())
+    }
+}
+
+/// A step to a neighbouring hexagon, see [`Hex`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexDirection {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+impl HexDirection {
+    /// Clockwise from east.
+    pub const ALL: [HexDirection; 6] = [
+        HexDirection::East,
+        HexDirection::SouthEast,
+        HexDirection::SouthWest,
+        HexDirection::West,
+        HexDirection::NorthWest,
+        HexDirection::NorthEast,
+    ];
+
+    /// The change in cube coordinates for a step this way.
+    pub fn offset(self) -> (i32, i32, i32) {
+        match self {
+            HexDirection::East => (1, -1, 0),
+            HexDirection::SouthEast => (0, -1, 1),
+            HexDirection::SouthWest => (-1, 0, 1),
+            HexDirection::West => (-1, 1, 0),
+            HexDirection::NorthWest => (0, 1, -1),
+            HexDirection::NorthEast => (1, 0, -1),
+        }
+    }
+
+    pub fn opposite(self) -> HexDirection {
+        let index = HexDirection::ALL.iter().position(|&d| d == self).unwrap();
+        HexDirection::ALL[(index + 3) % 6]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{around, Heading, Hex, HexDirection, Vec2};
+
+    #[test]
+    fn should_rotate_by_quarter_turns() {
+        let v = Vec2::new(10, 4);
+
+        assert_eq!(v.rotate_left(1), Vec2::new(-4, 10));
+        assert_eq!(v.rotate_right(1), Vec2::new(4, -10));
+        assert_eq!(v.rotate_left(2), Vec2::new(-10, -4));
+        assert_eq!(v.rotate_right(3), v.rotate_left(1));
+        assert_eq!(v.rotate_left(-5), v.rotate_right(1));
+        assert_eq!(v.rotate_left(4), v);
+    }
+
+    #[test]
+    fn should_measure_manhattan_distance() {
+        assert_eq!(Vec2::new(17, -8).manhattan(), 25);
+        assert_eq!((Vec2::new(1, 2) - Vec2::new(4, -2)).manhattan(), 7);
+    }
+
+    #[test]
+    fn should_find_everything_around_a_position() {
+        assert_eq!(around::<1>(), vec![[-1], [1]]);
+        assert_eq!(around::<2>().len(), 8);
+        assert_eq!(around::<4>().len(), 80);
+        assert!(!around::<4>().contains(&[0; 4]));
+        assert!(around::<3>().contains(&[1, -1, 0]));
+    }
+
+    #[test]
+    fn should_parse_headings() {
+        assert_eq!("W".parse::<Heading>(), Ok(Heading::West));
+        assert!("X".parse::<Heading>().is_err());
+        assert!("NE".parse::<Heading>().is_err());
+
+        assert_eq!(Heading::North.unit(), Vec2::new(0, 1));
+        for (idx, heading) in Heading::ALL.iter().enumerate() {
+            let next = Heading::ALL[(idx + 1) % 4];
+            assert_eq!(heading.unit().rotate_right(1), next.unit());
+        }
+    }
+
+    #[test]
+    fn should_step_between_hexagons() {
+        for &dir in HexDirection::ALL.iter() {
+            let next = Hex::ORIGIN.step(dir);
+            assert_eq!(next.x + next.y + next.z, 0);
+            assert_eq!(Hex::ORIGIN.distance(next), 1);
+            assert_eq!(next.step(dir.opposite()), Hex::ORIGIN);
+        }
+
+        let east = Hex::ORIGIN.step(HexDirection::East);
+        assert!(east.neighbours().contains(&Hex::ORIGIN));
+        assert_eq!(east.neighbours().len(), 6);
+    }
+
+    #[test]
+    fn should_convert_axial_coordinates() {
+        let hex = Hex::from_axial(3, -5);
+
+        assert_eq!(hex, Hex { x: 3, y: 2, z: -5 });
+        assert_eq!(hex.axial(), (3, -5));
+        assert_eq!(hex.distance(Hex::ORIGIN), 5);
+        assert_eq!(Hex::from_axial(2, 1).distance(Hex::from_axial(-1, 3)), 3);
+    }
+}
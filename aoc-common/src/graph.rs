@@ -0,0 +1,350 @@
+//! Directed graphs and bipartite matching.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// Identifies a node in the [`Graph`] that added it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(usize);
+
+impl NodeId {
+    /// Position of the node in the order nodes were added, handy for indexing side tables.
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// A cycle found while ordering a graph: each node has an edge to the next, and the last has one
+/// back to the first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle(pub Vec<NodeId>);
+
+/// A directed graph with a `N` on every node and an `E` on every edge.
+#[derive(Debug, Clone)]
+pub struct Graph<N, E = ()> {
+    nodes: Vec<N>,
+    successors: Vec<Vec<(NodeId, E)>>,
+    predecessors: Vec<Vec<NodeId>>,
+}
+
+impl<N, E> Default for Graph<N, E> {
+    fn default() -> Self {
+        Graph {
+            nodes: Vec::new(),
+            successors: Vec::new(),
+            predecessors: Vec::new(),
+        }
+    }
+}
+
+impl<N, E> Graph<N, E> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_node(&mut self, node: N) -> NodeId {
+        self.nodes.push(node);
+        self.successors.push(Vec::new());
+        self.predecessors.push(Vec::new());
+        NodeId(self.nodes.len() - 1)
+    }
+
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId, edge: E) {
+        self.successors[from.0].push((to, edge));
+        self.predecessors[to.0].push(from);
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Every node's id, in the order they were added.
+    pub fn ids(&self) -> impl Iterator<Item = NodeId> {
+        (0..self.nodes.len()).map(NodeId)
+    }
+
+    pub fn node(&self, id: NodeId) -> &N {
+        &self.nodes[id.0]
+    }
+
+    /// The nodes `id` has an edge to, along with the edges.
+    pub fn successors(&self, id: NodeId) -> &[(NodeId, E)] {
+        &self.successors[id.0]
+    }
+
+    /// The nodes with an edge to `id`.
+    pub fn predecessors(&self, id: NodeId) -> &[NodeId] {
+        &self.predecessors[id.0]
+    }
+
+    /// Orders the nodes so every edge points forwards, or finds a cycle that makes that
+    /// impossible.
+    pub fn topological_order(&self) -> Result<Vec<NodeId>, Cycle> {
+        let mut incoming = self.predecessors.iter().map(Vec::len).collect::<Vec<_>>();
+        let mut ready = self
+            .ids()
+            .filter(|id| incoming[id.0] == 0)
+            .collect::<VecDeque<_>>();
+        let mut order = Vec::with_capacity(self.len());
+
+        while let Some(id) = ready.pop_front() {
+            order.push(id);
+            for &(next, _) in self.successors(id) {
+                incoming[next.0] -= 1;
+                if incoming[next.0] == 0 {
+                    ready.push_back(next);
+                }
+            }
+        }
+
+        if order.len() == self.len() {
+            return Ok(order);
+        }
+
+        // every node left over has a predecessor that's also left over, so walking backwards
+        // through them has to come round to a node it's already seen
+        let mut path = Vec::new();
+        let mut seen = HashMap::new();
+        let mut id = self.ids().find(|id| incoming[id.0] > 0).unwrap();
+        while !seen.contains_key(&id) {
+            seen.insert(id, path.len());
+            path.push(id);
+            id = *self
+                .predecessors(id)
+                .iter()
+                .find(|p| incoming[p.0] > 0)
+                .unwrap();
+        }
+        let mut cycle = path.split_off(seen[&id]);
+        cycle.reverse();
+        cycle.rotate_right(1);
+        Err(Cycle(cycle))
+    }
+
+    /// The nodes reachable from `id` by following one or more edges, which includes `id`
+    /// itself if it's on a cycle.
+    pub fn descendants(&self, id: NodeId) -> HashSet<NodeId> {
+        self.reachable(id, |id| {
+            self.successors(id).iter().map(|&(next, _)| next).collect()
+        })
+    }
+
+    /// The nodes that can reach `id` by following one or more edges, which includes `id` itself
+    /// if it's on a cycle.
+    pub fn ancestors(&self, id: NodeId) -> HashSet<NodeId> {
+        self.reachable(id, |id| self.predecessors(id).to_vec())
+    }
+
+    fn reachable(&self, from: NodeId, next: impl Fn(NodeId) -> Vec<NodeId>) -> HashSet<NodeId> {
+        let mut found = HashSet::new();
+        let mut todo = next(from);
+        while let Some(id) = todo.pop() {
+            if found.insert(id) {
+                todo.extend(next(id));
+            }
+        }
+        found
+    }
+}
+
+/// A maximum matching between left and right nodes, see [`bipartite_matching`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matching<L, R> {
+    /// Each matched left node with its right node, in the order the left nodes were given.
+    pub pairs: Vec<(L, R)>,
+    /// Left nodes left without a right node.
+    pub unmatched: Vec<L>,
+    /// Matched left nodes that another matching of the same size pairs with a different right
+    /// node.
+    pub ambiguous: Vec<L>,
+}
+
+impl<L, R> Matching<L, R> {
+    /// Whether every left node is matched and there's no other way to match them all.
+    pub fn is_unique(&self) -> bool {
+        self.unmatched.is_empty() && self.ambiguous.is_empty()
+    }
+}
+
+/// Matches as many left nodes as possible to a distinct right node from their candidates, and
+/// works out which pairs are forced.
+///
+/// A left node's pair is ambiguous when swapping partners along an alternating path either comes
+/// back round to it or frees up a right node nobody was using.
+pub fn bipartite_matching<L, R, C>(candidates: impl IntoIterator<Item = (L, C)>) -> Matching<L, R>
+where
+    L: Clone,
+    R: Clone + Eq + Hash,
+    C: IntoIterator<Item = R>,
+{
+    let mut lefts = Vec::new();
+    let mut rights = Vec::new();
+    let mut right_ids = HashMap::new();
+    let mut adjacent = Vec::new();
+    for (left, candidates) in candidates {
+        lefts.push(left);
+        let ids = candidates
+            .into_iter()
+            .map(|right| {
+                *right_ids.entry(right.clone()).or_insert_with(|| {
+                    rights.push(right);
+                    rights.len() - 1
+                })
+            })
+            .collect::<Vec<_>>();
+        adjacent.push(ids);
+    }
+
+    // Kuhn's algorithm: look for an augmenting path from each left node in turn
+    let mut owner = vec![None; rights.len()];
+    for left in 0..lefts.len() {
+        augment(left, &adjacent, &mut owner, &mut vec![false; rights.len()]);
+    }
+    let mut partner = vec![None; lefts.len()];
+    for (right, &left) in owner.iter().enumerate() {
+        if let Some(left) = left {
+            partner[left] = Some(right);
+        }
+    }
+
+    // follow each unused candidate to whoever holds it, or to a sink if nobody does
+    let mut swaps: Graph<()> = Graph::new();
+    let ids = lefts.iter().map(|_| swaps.add_node(())).collect::<Vec<_>>();
+    let free = swaps.add_node(());
+    for (left, candidates) in adjacent.iter().enumerate() {
+        for &right in candidates.iter().filter(|&&r| Some(r) != partner[left]) {
+            let to = owner[right].map_or(free, |other| ids[other]);
+            swaps.add_edge(ids[left], to, ());
+        }
+    }
+
+    let mut matching = Matching {
+        pairs: Vec::new(),
+        unmatched: Vec::new(),
+        ambiguous: Vec::new(),
+    };
+    for ((left, right), id) in lefts.into_iter().zip(partner).zip(ids) {
+        match right {
+            None => matching.unmatched.push(left),
+            Some(right) => {
+                let reachable = swaps.descendants(id);
+                if reachable.contains(&id) || reachable.contains(&free) {
+                    matching.ambiguous.push(left.clone());
+                }
+                matching.pairs.push((left, rights[right].clone()));
+            }
+        }
+    }
+    matching
+}
+
+fn augment(
+    left: usize,
+    adjacent: &[Vec<usize>],
+    owner: &mut [Option<usize>],
+    visited: &mut [bool],
+) -> bool {
+    for &right in adjacent[left].iter() {
+        if visited[right] {
+            continue;
+        }
+        visited[right] = true;
+        if owner[right].is_none_or(|other| augment(other, adjacent, owner, visited)) {
+            owner[right] = Some(left);
+            return true;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bipartite_matching, Cycle, Graph, NodeId};
+    use std::collections::HashSet;
+
+    /// a -> b -> c, a -> c and d -> c.
+    fn diamond() -> (Graph<char>, Vec<NodeId>) {
+        let mut graph = Graph::new();
+        let ids = "abcd"
+            .chars()
+            .map(|c| graph.add_node(c))
+            .collect::<Vec<_>>();
+        for &(from, to) in [(0, 1), (1, 2), (0, 2), (3, 2)].iter() {
+            graph.add_edge(ids[from], ids[to], ());
+        }
+        (graph, ids)
+    }
+
+    #[test]
+    fn should_order_edges_forwards() {
+        let (graph, _) = diamond();
+        let order = graph.topological_order().unwrap();
+        let position = |c| order.iter().position(|&id| *graph.node(id) == c).unwrap();
+
+        assert_eq!(order.len(), 4);
+        assert!(position('a') < position('b'));
+        assert!(position('b') < position('c'));
+        assert!(position('d') < position('c'));
+    }
+
+    #[test]
+    fn should_report_a_cycle() {
+        let (mut graph, ids) = diamond();
+        let e = graph.add_node('e');
+        graph.add_edge(ids[2], e, ());
+        graph.add_edge(e, ids[1], ());
+
+        assert_eq!(
+            graph.topological_order(),
+            Err(Cycle(vec![ids[1], ids[2], e]))
+        );
+    }
+
+    #[test]
+    fn should_find_ancestors_and_descendants() {
+        let (graph, ids) = diamond();
+        let set = |idx: &[usize]| idx.iter().map(|&i| ids[i]).collect::<HashSet<_>>();
+
+        assert_eq!(graph.descendants(ids[0]), set(&[1, 2]));
+        assert_eq!(graph.ancestors(ids[2]), set(&[0, 1, 3]));
+        assert_eq!(graph.ancestors(ids[3]), set(&[]));
+    }
+
+    #[test]
+    fn should_match_by_elimination() {
+        let matching = bipartite_matching(vec![
+            ("x", vec![1, 2, 3]),
+            ("y", vec![1]),
+            ("z", vec![1, 3]),
+        ]);
+
+        assert!(matching.is_unique());
+        assert_eq!(matching.pairs, vec![("x", 2), ("y", 1), ("z", 3)]);
+    }
+
+    #[test]
+    fn should_report_ambiguous_pairs() {
+        let matching =
+            bipartite_matching(vec![("x", vec![1, 2]), ("y", vec![1, 2]), ("z", vec![3])]);
+        assert_eq!(matching.ambiguous, vec!["x", "y"]);
+
+        let matching = bipartite_matching(vec![("x", vec![1, 2]), ("y", vec![1])]);
+        assert!(matching.is_unique());
+
+        let matching = bipartite_matching(vec![("x", vec![1, 2, 3]), ("y", vec![1])]);
+        assert_eq!(matching.ambiguous, vec!["x"]);
+    }
+
+    #[test]
+    fn should_report_unmatched_nodes() {
+        let matching = bipartite_matching(vec![("x", vec![1]), ("y", vec![1]), ("z", vec![])]);
+
+        assert_eq!(matching.pairs.len(), 1);
+        assert_eq!(matching.unmatched.len(), 2);
+        assert!(!matching.is_unique());
+    }
+}
@@ -0,0 +1,415 @@
+use crate::geometry::{DIRECTIONS, ORTHOGONAL};
+use crate::ParseError;
+use std::{
+    fmt::{self, Display},
+    iter::successors,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+/// A position on a grid as `(x, y)`, i.e. column then row, with `(0, 0)` in the top left.
+pub type Pos = (usize, usize);
+
+/// A cell of a grid that's written as a single character.
+pub trait Cell: Sized {
+    /// The cell a character in the puzzle input stands for, if any.
+    fn from_char(c: char) -> Option<Self>;
+    fn to_char(&self) -> char;
+}
+
+impl Cell for char {
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+/// Which of the surrounding cells count as neighbours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Adjacency {
+    /// The four cells sharing an edge.
+    Orthogonal,
+    /// All eight surrounding cells, diagonals included.
+    All,
+}
+
+impl Adjacency {
+    /// `(dx, dy)` offsets of the neighbouring cells.
+    pub fn directions(self) -> &'static [(i64, i64)] {
+        match self {
+            Adjacency::Orthogonal => &ORTHOGONAL,
+            Adjacency::All => &DIRECTIONS,
+        }
+    }
+}
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid2D<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid2D<T> {
+    /// Builds a grid from its cells in row order.
+    ///
+    /// Panics if the cells don't fill a whole number of rows.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Grid2D<T> {
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        assert_eq!(width * height, cells.len(), "cells must fill whole rows");
+        Grid2D {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Builds a grid by calling `f` with every position in row order.
+    pub fn from_fn(width: usize, height: usize, f: impl FnMut(Pos) -> T) -> Grid2D<T> {
+        let cells = positions(width, height).map(f).collect();
+        Grid2D::from_vec(width, cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): Pos) -> Option<&T> {
+        if x < self.width && y < self.height {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (x, y): Pos) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    /// Every position in row order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        positions(self.width, self.height)
+    }
+
+    /// Every cell in row order.
+    pub fn cells(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Every cell along with its position, in row order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks would panic on an empty grid
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// The position `(dx, dy)` away from `pos`, if it's on the grid.
+    pub fn offset(&self, (x, y): Pos, (dx, dy): (i64, i64)) -> Option<Pos> {
+        let x = (x as i64).checked_add(dx)?;
+        let y = (y as i64).checked_add(dy)?;
+        if (0..self.width as i64).contains(&x) && (0..self.height as i64).contains(&y) {
+            Some((x as usize, y as usize))
+        } else {
+            None
+        }
+    }
+
+    /// The position `(dx, dy)` away from `pos`, wrapping around the edges.
+    pub fn wrapping_offset(&self, (x, y): Pos, (dx, dy): (i64, i64)) -> Pos {
+        (
+            (x as i64 + dx).rem_euclid(self.width as i64) as usize,
+            (y as i64 + dy).rem_euclid(self.height as i64) as usize,
+        )
+    }
+
+    /// Neighbouring positions of `pos` that are on the grid.
+    pub fn neighbours(&self, pos: Pos, adjacency: Adjacency) -> impl Iterator<Item = Pos> + '_ {
+        adjacency
+            .directions()
+            .iter()
+            .filter_map(move |&d| self.offset(pos, d))
+    }
+
+    /// Neighbouring positions of `pos`, wrapping around the edges.
+    pub fn wrapping_neighbours(
+        &self,
+        pos: Pos,
+        adjacency: Adjacency,
+    ) -> impl Iterator<Item = Pos> + '_ {
+        adjacency
+            .directions()
+            .iter()
+            .map(move |&d| self.wrapping_offset(pos, d))
+    }
+
+    /// Positions in a straight line from `from` (not included) in steps of `dir`, until the edge.
+    pub fn walk(&self, from: Pos, dir: (i64, i64)) -> impl Iterator<Item = Pos> + '_ {
+        successors(self.offset(from, dir), move |&pos| self.offset(pos, dir))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid2D<U> {
+        Grid2D::from_vec(self.width, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T: Clone> Grid2D<T> {
+    pub fn new(width: usize, height: usize, cell: T) -> Grid2D<T> {
+        Grid2D::from_vec(width, vec![cell; width * height])
+    }
+
+    /// The grid turned a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Grid2D<T> {
+        Grid2D::from_fn(self.height, self.width, |(x, y)| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    /// The grid mirrored left to right.
+    pub fn flip_horizontal(&self) -> Grid2D<T> {
+        Grid2D::from_fn(self.width, self.height, |(x, y)| {
+            self[(self.width - 1 - x, y)].clone()
+        })
+    }
+
+    /// The grid mirrored top to bottom.
+    pub fn flip_vertical(&self) -> Grid2D<T> {
+        Grid2D::from_fn(self.width, self.height, |(x, y)| {
+            self[(x, self.height - 1 - y)].clone()
+        })
+    }
+
+    /// All eight ways the grid can be rotated and flipped, starting with the grid as it is.
+    pub fn orientations(&self) -> Vec<Grid2D<T>> {
+        let mut res = Vec::with_capacity(8);
+        for start in [self.clone(), self.flip_horizontal()].iter() {
+            let mut current = start.clone();
+            for _ in 0..4 {
+                let next = current.rotate_cw();
+                res.push(current);
+                current = next;
+            }
+        }
+        res
+    }
+
+    /// The `width` by `height` section of the grid with its top left corner at `(x, y)`.
+    pub fn crop(&self, (x, y): Pos, width: usize, height: usize) -> Grid2D<T> {
+        Grid2D::from_fn(width, height, |(dx, dy)| self[(x + dx, y + dy)].clone())
+    }
+}
+
+impl<T: Cell> Grid2D<T> {
+    /// Parses one row per line and one cell per character, rejecting ragged rows.
+    pub fn parse(input: &str) -> Result<Grid2D<T>, ParseError> {
+        let mut width = None;
+        let mut cells = Vec::new();
+
+        for line in input.lines() {
+            let mut len = 0;
+            for (idx, c) in line.char_indices() {
+                let cell = T::from_char(c).ok_or_else(|| {
+                    let text = &line[idx..idx + c.len_utf8()];
+                    ParseError::new(text, format!("unexpected cell {:?}", c))
+                })?;
+                cells.push(cell);
+                len += 1;
+            }
+
+            let width = *width.get_or_insert(len);
+            if width != len {
+                let reason = format!("expected a row of {} cells, found {}", width, len);
+                return Err(ParseError::new(line, reason));
+            }
+        }
+
+        Ok(Grid2D::from_vec(width.unwrap_or(0), cells))
+    }
+}
+
+impl<T: Cell> FromStr for Grid2D<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid2D::parse(s)
+    }
+}
+
+impl<T: Cell> Display for Grid2D<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell.to_char())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> Index<Pos> for Grid2D<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid2D<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+
+fn positions(width: usize, height: usize) -> impl Iterator<Item = Pos> {
+    (0..height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Adjacency, Cell, Grid2D};
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Square {
+        Open,
+        Tree,
+    }
+
+    impl Cell for Square {
+        fn from_char(c: char) -> Option<Self> {
+            match c {
+                '.' => Some(Square::Open),
+                '#' => Some(Square::Tree),
+                _ => None,
+            }
+        }
+
+        fn to_char(&self) -> char {
+            match self {
+                Square::Open => '.',
+                Square::Tree => '#',
+            }
+        }
+    }
+
+    fn grid(input: &str) -> Grid2D<char> {
+        input.parse().unwrap()
+    }
+
+    #[test]
+    fn should_parse_typed_cells() {
+        let g = Grid2D::<Square>::parse("#.\n.#").unwrap();
+        assert_eq!((g.width(), g.height()), (2, 2));
+        assert_eq!(g[(0, 0)], Square::Tree);
+        assert_eq!(g[(1, 0)], Square::Open);
+        assert_eq!(g.to_string(), "#.\n.#");
+    }
+
+    #[test]
+    fn should_reject_unknown_cells_and_ragged_rows() {
+        let input = "#.\n.x";
+        let err = Grid2D::<Square>::parse(input).unwrap_err().locate(3, input);
+        assert_eq!((err.line(), err.column(), err.text()), (2, 2, "x"));
+
+        let input = "#.\n.#.";
+        let err = Grid2D::<Square>::parse(input).unwrap_err().locate(3, input);
+        assert_eq!((err.line(), err.column(), err.text()), (2, 1, ".#."));
+    }
+
+    #[test]
+    fn should_view_rows_and_columns() {
+        let g = grid("abc\ndef");
+        assert_eq!(g.row(1), &['d', 'e', 'f']);
+        assert_eq!(g.column(2).collect::<String>(), "cf");
+        assert_eq!(g.rows().count(), 2);
+    }
+
+    #[test]
+    fn should_only_yield_neighbours_on_the_grid() {
+        let g = grid("abc\ndef\nghi");
+        let around = |pos, adjacency| {
+            let mut n = g
+                .neighbours(pos, adjacency)
+                .map(|p| g[p])
+                .collect::<Vec<_>>();
+            n.sort_unstable();
+            n.into_iter().collect::<String>()
+        };
+
+        assert_eq!(around((0, 0), Adjacency::All), "bde");
+        assert_eq!(around((0, 0), Adjacency::Orthogonal), "bd");
+        assert_eq!(around((1, 1), Adjacency::All), "abcdfghi");
+        assert_eq!(around((2, 1), Adjacency::Orthogonal), "cei");
+    }
+
+    #[test]
+    fn should_wrap_neighbours_around_the_edges() {
+        let g = grid("abc\ndef\nghi");
+        let mut n = g
+            .wrapping_neighbours((0, 0), Adjacency::Orthogonal)
+            .map(|p| g[p])
+            .collect::<Vec<_>>();
+        n.sort_unstable();
+        assert_eq!(n, vec!['b', 'c', 'd', 'g']);
+    }
+
+    #[test]
+    fn should_walk_to_the_edge() {
+        let g = grid("abc\ndef\nghi");
+        let seen = g.walk((0, 0), (1, 1)).map(|p| g[p]).collect::<String>();
+        assert_eq!(seen, "ei");
+        assert_eq!(g.walk((0, 0), (-1, 0)).count(), 0);
+    }
+
+    #[test]
+    fn should_rotate_and_flip() {
+        let g = grid("ab\ncd\nef");
+        assert_eq!(g.rotate_cw().to_string(), "eca\nfdb");
+        assert_eq!(g.flip_horizontal().to_string(), "ba\ndc\nfe");
+        assert_eq!(g.flip_vertical().to_string(), "ef\ncd\nab");
+        assert_eq!(g.rotate_cw().rotate_cw().rotate_cw().rotate_cw(), g);
+    }
+
+    #[test]
+    fn should_list_all_eight_orientations() {
+        let mut orientations = grid("ab\ncd").orientations();
+        assert_eq!(orientations[0], grid("ab\ncd"));
+
+        orientations.sort_by_key(|g| g.to_string());
+        orientations.dedup();
+        assert_eq!(orientations.len(), 8);
+    }
+
+    #[test]
+    fn should_crop() {
+        let g = grid("abc\ndef\nghi");
+        assert_eq!(g.crop((1, 1), 2, 2).to_string(), "ef\nhi");
+    }
+}
@@ -0,0 +1,124 @@
+use std::{
+    env,
+    ffi::OsString,
+    fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    process::exit,
+};
+
+/// Environment variable that can point at an input file when no path is passed on the command line.
+pub const INPUT_VAR: &str = "AOC_INPUT";
+
+/// Where a puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// Treats `-` as stdin and anything else as a file path.
+    pub fn from_arg(arg: impl Into<OsString>) -> Source {
+        let arg = arg.into();
+        match arg.to_str() {
+            Some("-") => Source::Stdin,
+            _ => Source::File(arg.into()),
+        }
+    }
+
+    /// Picks the explicit argument if there is one, then `AOC_INPUT`, then `default`.
+    pub fn resolve(arg: Option<OsString>, default: &Path) -> Source {
+        arg.or_else(|| env::var_os(INPUT_VAR))
+            .map(Source::from_arg)
+            .unwrap_or_else(|| Source::File(default.to_path_buf()))
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            Source::File(path) => fs::read_to_string(path),
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "stdin"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Evens out the differences editors and operating systems leave in a saved input: `\r\n` line
+/// endings become `\n`, whitespace at the end of each line goes and trailing blank lines collapse
+/// into a single final newline.
+///
+/// Lines keep their numbers, so parse errors still point at the right place.
+pub fn normalise(raw: &str) -> String {
+    let mut text = String::with_capacity(raw.len());
+    for line in raw.lines() {
+        text.push_str(line.trim_end());
+        text.push('\n');
+    }
+
+    text.truncate(text.trim_end_matches('\n').len());
+    if !text.is_empty() {
+        text.push('\n');
+    }
+    text
+}
+
+/// Reads the input for a day's binary from its first argument, `AOC_INPUT` or `default`,
+/// exiting with a message if it can't be read.
+pub fn load(default: &str) -> String {
+    let source = Source::resolve(env::args_os().nth(1), Path::new(default));
+    source.read().unwrap_or_else(|e| {
+        eprintln!("failed to read input from {}: {}", source, e);
+        exit(1);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{normalise, Source};
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn should_treat_dash_as_stdin() {
+        assert_eq!(Source::from_arg("-"), Source::Stdin);
+        assert_eq!(
+            Source::from_arg("input"),
+            Source::File(PathBuf::from("input"))
+        );
+    }
+
+    #[test]
+    fn should_prefer_explicit_argument() {
+        let source = Source::resolve(Some("other".into()), Path::new("input"));
+        assert_eq!(source, Source::File(PathBuf::from("other")));
+    }
+
+    #[test]
+    fn should_normalise_line_endings() {
+        assert_eq!(normalise("a\r\nb\r\n\r\nc\r\n"), "a\nb\n\nc\n");
+        assert_eq!(normalise("a\nb"), "a\nb\n");
+    }
+
+    #[test]
+    fn should_drop_trailing_blank_lines() {
+        assert_eq!(normalise("a\nb\n\n\n"), "a\nb\n");
+        assert_eq!(normalise("a\r\n \r\n\t\n"), "a\n");
+        assert_eq!(normalise("\n\n"), "");
+    }
+
+    #[test]
+    fn should_strip_stray_whitespace() {
+        assert_eq!(normalise("a  \n  b\t\n \nc"), "a\n  b\n\nc\n");
+    }
+}
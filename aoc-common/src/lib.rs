@@ -0,0 +1,23 @@
+//! Helpers shared between the daily puzzle crates.
+
+pub mod arith;
+pub mod automaton;
+pub mod config;
+mod error;
+pub mod explain;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod number_theory;
+pub mod parse;
+mod part;
+pub mod progress;
+pub mod render;
+mod solution;
+pub mod testing;
+pub mod variants;
+
+pub use error::{ParseError, SolveError, Unsolvable};
+pub use part::Part;
+pub use solution::{parse_input, print_answers, run, solve, solve_with, Report, Solution};
@@ -0,0 +1,157 @@
+//! Modular arithmetic on `u64`s, widening to `u128` wherever a product could overflow.
+
+use std::{collections::HashMap, convert::TryFrom};
+
+/// `a * b % modulus` without overflowing.
+pub fn mod_mul(a: u64, b: u64, modulus: u64) -> u64 {
+    (a as u128 * b as u128 % modulus as u128) as u64
+}
+
+/// `base` to the power `exp`, modulo `modulus`, by repeated squaring.
+pub fn mod_pow(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let mut base = base % modulus;
+    let mut result = 1 % modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mod_mul(result, base, modulus);
+        }
+        base = mod_mul(base, base, modulus);
+        exp >>= 1;
+    }
+    result
+}
+
+/// The greatest common divisor `g` of `a` and `b` along with Bézout coefficients `x` and `y`
+/// such that `a * x + b * y == g`, as `(g, x, y)`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..modulus` with `a * x ≡ 1`, if `a` and `modulus` are coprime.
+pub fn mod_inverse(a: u64, modulus: u64) -> Option<u64> {
+    let (g, x, _) = extended_gcd(a as i128, modulus as i128);
+    match g {
+        1 => Some(x.rem_euclid(modulus as i128) as u64),
+        _ => None,
+    }
+}
+
+/// Solves a system of congruences `x ≡ residue (mod modulus)`, returning the smallest
+/// non-negative solution and the modulus it repeats with, the lcm of all the moduli.
+///
+/// The moduli don't need to be coprime. Returns `None` if the congruences contradict each
+/// other, a modulus is zero or the lcm doesn't fit in a `u64`.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, u64)>) -> Option<(u64, u64)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(x, lcm), (residue, modulus)| {
+            if modulus == 0 {
+                return None;
+            }
+            let residue = (residue as i128).rem_euclid(modulus as i128);
+
+            // x + lcm * t ≡ residue (mod modulus), so lcm * t ≡ residue - x, which has a
+            // solution only if the gcd divides the difference
+            let (g, inverse, _) = extended_gcd(lcm as i128, modulus as i128);
+            let diff = residue - x as i128;
+            if diff % g != 0 {
+                return None;
+            }
+            let step = modulus as i128 / g;
+            let t = (diff / g).rem_euclid(step) as u128 * inverse.rem_euclid(step) as u128
+                % step as u128;
+
+            let merged = u64::try_from(lcm as u128 * step as u128).ok()?;
+            Some(((x as u128 + lcm as u128 * t) as u64, merged))
+        })
+}
+
+/// The smallest `x` with `base^x ≡ target (mod modulus)`, found with baby-step giant-step in
+/// about `sqrt(modulus)` steps.
+///
+/// `base` needs to be coprime with `modulus`, otherwise this returns `None`, as it does when no
+/// such `x` exists.
+pub fn discrete_log(base: u64, target: u64, modulus: u64) -> Option<u64> {
+    let target = target % modulus;
+    let steps = (modulus as f64).sqrt().ceil() as u64;
+
+    // baby steps: base^j for each j below `steps`, keeping the smallest j for each value
+    let mut baby = HashMap::new();
+    let mut power = 1 % modulus;
+    for j in 0..steps {
+        baby.entry(power).or_insert(j);
+        power = mod_mul(power, base, modulus);
+    }
+
+    // giant steps: target * base^(-steps * i) for increasing i
+    let giant = mod_inverse(mod_pow(base, steps, modulus), modulus)?;
+    let mut gamma = target;
+    for i in 0..steps {
+        if let Some(&j) = baby.get(&gamma) {
+            return Some(i * steps + j);
+        }
+        gamma = mod_mul(gamma, giant, modulus);
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{crt, discrete_log, extended_gcd, mod_inverse, mod_pow};
+
+    #[test]
+    fn should_pow_without_overflowing() {
+        assert_eq!(mod_pow(7, 8, 20201227), 5764801);
+        assert_eq!(mod_pow(u64::MAX - 1, 2, u64::MAX), 1);
+        assert_eq!(mod_pow(5, 0, 1), 0);
+    }
+
+    #[test]
+    fn should_find_bezout_coefficients() {
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+    }
+
+    #[test]
+    fn should_invert_coprime_numbers_only() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(6, 9), None);
+    }
+
+    #[test]
+    fn should_solve_coprime_congruences() {
+        // the bus schedule `17,x,13,19` from day 13
+        let congruences = vec![(0, 17), (-2, 13), (-3, 19)];
+        assert_eq!(crt(congruences), Some((3417, 17 * 13 * 19)));
+    }
+
+    #[test]
+    fn should_solve_congruences_sharing_factors() {
+        assert_eq!(crt(vec![(2, 6), (8, 10)]), Some((8, 30)));
+        assert_eq!(crt(vec![(1, 6), (2, 4)]), None);
+    }
+
+    #[test]
+    fn should_find_the_smallest_log() {
+        assert_eq!(discrete_log(7, 5764801, 20201227), Some(8));
+        assert_eq!(discrete_log(7, 17807724, 20201227), Some(11));
+        assert_eq!(discrete_log(2, 1, 7), Some(0));
+        assert_eq!(discrete_log(2, 3, 7), None);
+    }
+}
@@ -0,0 +1,69 @@
+use crate::ParseError;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Parses `text` as a `T`, blaming `text` if it isn't one.
+pub fn value<T: FromStr>(text: &str) -> Result<T, ParseError>
+where
+    T::Err: Display,
+{
+    text.parse().map_err(|e| ParseError::new(text, e))
+}
+
+/// Parses one number per line.
+pub fn numbers<T: FromStr>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T::Err: Display,
+{
+    input.lines().map(|l| value(l.trim())).collect()
+}
+
+/// Parses a single line of comma separated numbers.
+pub fn comma_separated<T: FromStr>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T::Err: Display,
+{
+    input.trim().split(',').map(|n| value(n.trim())).collect()
+}
+
+/// Splits input into its groups of lines, which are separated by one or more blank lines.
+pub fn sections(input: &str) -> impl Iterator<Item = &str> {
+    input
+        .split("\n\n")
+        .map(|s| s.trim_matches('\n'))
+        .filter(|s| !s.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{comma_separated, numbers, sections};
+
+    #[test]
+    fn should_parse_numbers() {
+        assert_eq!(numbers::<usize>("1\n22\n333\n"), Ok(vec![1, 22, 333]));
+    }
+
+    #[test]
+    fn should_blame_the_bad_number() {
+        let input = "1\n2x\n";
+        let err = numbers::<usize>(input).unwrap_err().locate(1, input);
+        assert_eq!((err.line(), err.column(), err.text()), (2, 1, "2x"));
+    }
+
+    #[test]
+    fn should_parse_comma_separated() {
+        assert_eq!(comma_separated::<usize>("0,3,6\n"), Ok(vec![0, 3, 6]));
+
+        let input = "0,3,x";
+        let err = comma_separated::<usize>(input)
+            .unwrap_err()
+            .locate(15, input);
+        assert_eq!((err.line(), err.column(), err.text()), (1, 5, "x"));
+    }
+
+    #[test]
+    fn should_split_sections_on_blank_lines() {
+        let input = "a\nb\n\nc\n\n\n\nd\n";
+        assert_eq!(sections(input).collect::<Vec<_>>(), vec!["a\nb", "c", "d"]);
+    }
+}
@@ -0,0 +1,234 @@
+//! Progress bars and timeouts for the long-running simulations.
+//!
+//! A solver loop makes a [`Task`] and ticks it once per step. What a tick does depends on the
+//! [`Monitor`] the thread was given with [`monitor`]: by default nothing is drawn and nothing
+//! times out, so tests and library callers see no difference. Once a timeout passes every tick
+//! returns false, so the loop can stop early, and [`run`](crate::run) reports the part as timed
+//! out instead of whatever answer it got to.
+
+use crate::{Solution, SolveError};
+use indicatif::{ProgressBar, ProgressStyle};
+use std::{
+    cell::Cell,
+    error::Error,
+    fmt::{self, Display},
+    time::{Duration, Instant},
+};
+
+/// How progress is shown and how long a part may run for, see [`monitor`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Monitor {
+    /// Whether to draw a bar on stderr for each task.
+    pub bar: bool,
+    /// How long a part can run before its tasks are cancelled.
+    pub timeout: Option<Duration>,
+}
+
+thread_local! {
+    static MONITOR: Cell<Monitor> = const { Cell::new(Monitor { bar: false, timeout: None }) };
+    static DEADLINE: Cell<Option<Instant>> = const { Cell::new(None) };
+    static CANCELLED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Sets how tasks on this thread are shown and timed out from now on.
+pub fn monitor(monitor: Monitor) {
+    MONITOR.with(|m| m.set(monitor));
+}
+
+/// A part that ran past the [`Monitor`]'s timeout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timeout {
+    day: u8,
+    after: Duration,
+}
+
+impl Timeout {
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// The timeout that ran out.
+    pub fn after(&self) -> Duration {
+        self.after
+    }
+}
+
+impl Display for Timeout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {}: gave up after {:?}", self.day, self.after)
+    }
+}
+
+impl Error for Timeout {}
+
+/// Runs one part of `S`, failing if it was cancelled by the timeout.
+pub(crate) fn timed<S: Solution, T>(f: impl FnOnce() -> T) -> Result<T, SolveError> {
+    let timeout = MONITOR.with(Cell::get).timeout;
+    DEADLINE.with(|d| d.set(timeout.map(|t| Instant::now() + t)));
+    CANCELLED.with(|c| c.set(false));
+
+    let value = f();
+
+    DEADLINE.with(|d| d.set(None));
+    match timeout.filter(|_| CANCELLED.with(|c| c.replace(false))) {
+        Some(after) => Err(SolveError::Timeout(Timeout { day: S::DAY, after })),
+        None => Ok(value),
+    }
+}
+
+/// A loop reporting its progress, see the [module docs](self).
+#[derive(Debug)]
+pub struct Task {
+    bar: Option<ProgressBar>,
+    deadline: Option<Instant>,
+    done: u64,
+    // the bar and the clock are only looked at every `stride` ticks, to keep ticking cheap
+    stride: u64,
+    next_check: u64,
+}
+
+impl Task {
+    /// A task of `total` steps for `S`, labelled with what it's doing.
+    pub fn new<S: Solution>(label: &str, total: u64) -> Task {
+        let style = ProgressStyle::default_bar()
+            .template("{msg} [{bar:40}] {pos}/{len} ({eta} left)")
+            .progress_chars("=> ");
+        Task::with_bar::<S>(label, total / 1000, || {
+            ProgressBar::new(total).with_style(style)
+        })
+    }
+
+    /// A task for `S` that can't tell how many steps it'll take.
+    pub fn unbounded<S: Solution>(label: &str) -> Task {
+        let style = ProgressStyle::default_spinner().template("{spinner} {msg}: {pos} steps");
+        Task::with_bar::<S>(label, 1024, || ProgressBar::new_spinner().with_style(style))
+    }
+
+    fn with_bar<S: Solution>(label: &str, stride: u64, bar: impl FnOnce() -> ProgressBar) -> Task {
+        let bar = MONITOR.with(Cell::get).bar.then(|| {
+            let bar = bar();
+            bar.set_message(&format!("day {}: {}", S::DAY, label));
+            bar
+        });
+        let stride = stride.max(1);
+        Task {
+            bar,
+            deadline: DEADLINE.with(Cell::get),
+            done: 0,
+            stride,
+            next_check: stride,
+        }
+    }
+
+    /// Records a step as done, returning false if the loop should stop because it's run out of
+    /// time.
+    #[inline]
+    pub fn tick(&mut self) -> bool {
+        self.done += 1;
+        self.done < self.next_check || self.check()
+    }
+
+    fn check(&mut self) -> bool {
+        if let Some(bar) = &self.bar {
+            bar.set_position(self.done);
+        }
+        if self.deadline.is_some_and(|d| Instant::now() >= d) {
+            CANCELLED.with(|c| c.set(true));
+        }
+        if CANCELLED.with(Cell::get) {
+            // leave `next_check` where it is so every tick from now on comes back here
+            return false;
+        }
+        self.next_check = self.done + self.stride;
+        true
+    }
+}
+
+impl Drop for Task {
+    fn drop(&mut self) {
+        if let Some(bar) = &self.bar {
+            bar.finish_and_clear();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{monitor, Monitor, Task};
+    use crate::{config::NoParams, run, ParseError, Part, Solution, SolveError};
+    use std::time::Duration;
+
+    struct Count;
+
+    impl Solution for Count {
+        const DAY: u8 = 5;
+
+        type Params = NoParams;
+        type Parsed = u64;
+        type Answer1 = u64;
+        type Answer2 = u64;
+
+        fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+            crate::parse::value(input.trim())
+        }
+
+        /// Counts up to the input.
+        fn part1(&steps: &Self::Parsed, _: &Self::Params) -> Result<u64, SolveError> {
+            let mut task = Task::new::<Count>("counting", steps);
+            let mut count = 0;
+            while count < steps && task.tick() {
+                count += 1;
+            }
+            Ok(count)
+        }
+
+        /// Counts forever, or until it's stopped.
+        fn part2(_: &Self::Parsed, _: &Self::Params) -> Result<u64, SolveError> {
+            let mut task = Task::unbounded::<Count>("counting");
+            let mut count = 0;
+            while task.tick() {
+                count += 1;
+            }
+            Ok(count)
+        }
+    }
+
+    #[test]
+    fn should_run_to_completion_by_default() {
+        let reports = run::<Count>(&[Part::One], "100000", &NoParams {});
+        assert_eq!(reports[0].answer.as_deref(), Ok("100000"));
+    }
+
+    #[test]
+    fn should_stop_once_the_timeout_passes() {
+        monitor(Monitor {
+            bar: false,
+            timeout: Some(Duration::from_millis(20)),
+        });
+        let reports = run::<Count>(&Part::ALL, "100", &NoParams {});
+
+        assert_eq!(reports[0].answer.as_deref(), Ok("100"));
+        match &reports[1].answer {
+            Err(SolveError::Timeout(timeout)) => {
+                assert_eq!(timeout.to_string(), "day 5: gave up after 20ms")
+            }
+            other => panic!("expected a timeout, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_keep_stopping_once_cancelled() {
+        monitor(Monitor {
+            bar: false,
+            timeout: Some(Duration::ZERO),
+        });
+        let result = super::timed::<Count, _>(|| {
+            let mut task = Task::new::<Count>("counting", 10);
+            assert!(!task.tick());
+            assert!(!task.tick());
+        });
+
+        assert!(matches!(result, Err(SolveError::Timeout(_))));
+        assert!(Task::new::<Count>("counting", 10).tick());
+    }
+}
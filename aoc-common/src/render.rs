@@ -0,0 +1,267 @@
+//! Drawing grids as text or image frames, for watching a simulation run.
+//!
+//! Frames are numbered in the order they're written, so a sequence of them can be stitched
+//! into an animation with e.g. `convert frame*.ppm out.gif`.
+
+use crate::config::{Config, ConfigError};
+use crate::grid::{Cell, Grid2D};
+use crate::{parse_input, ParseError, Part, Solution};
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    fs, io,
+    path::PathBuf,
+};
+
+/// A cell that can be drawn in an image frame.
+pub trait Paint: Cell {
+    /// The cell's colour as red, green and blue.
+    fn rgb(&self) -> [u8; 3];
+
+    /// Whether the cell is black in a black and white frame, which by default is when its
+    /// colour is darker than mid grey.
+    fn is_ink(&self) -> bool {
+        let [r, g, b] = self.rgb().map(u32::from);
+        299 * r + 587 * g + 114 * b < 128_000
+    }
+}
+
+/// The file format frames are written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameFormat {
+    /// The grid's characters, as in the puzzle input.
+    Ascii,
+    /// A black and white binary portable bitmap.
+    Pbm,
+    /// A colour binary portable pixmap.
+    Ppm,
+}
+
+impl FrameFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            FrameFormat::Ascii => "txt",
+            FrameFormat::Pbm => "pbm",
+            FrameFormat::Ppm => "ppm",
+        }
+    }
+
+    /// Encodes `grid` in this format, drawing each cell as a `scale` by `scale` square in
+    /// image formats.
+    pub fn encode<T: Paint>(self, grid: &Grid2D<T>, scale: usize) -> Vec<u8> {
+        match self {
+            FrameFormat::Ascii => format!("{}\n", grid).into_bytes(),
+            FrameFormat::Pbm => pbm(grid, scale),
+            FrameFormat::Ppm => ppm(grid, scale),
+        }
+    }
+}
+
+fn pbm<T: Paint>(grid: &Grid2D<T>, scale: usize) -> Vec<u8> {
+    let (width, height) = (grid.width() * scale, grid.height() * scale);
+    let mut data = format!("P4\n{} {}\n", width, height).into_bytes();
+
+    for row in grid.rows() {
+        // each row of pixels is packed eight to a byte, most significant bit first
+        let mut bits = vec![0_u8; width.div_ceil(8)];
+        for (x, cell) in row.iter().enumerate() {
+            if cell.is_ink() {
+                for px in x * scale..(x + 1) * scale {
+                    bits[px / 8] |= 0x80 >> (px % 8);
+                }
+            }
+        }
+        for _ in 0..scale {
+            data.extend_from_slice(&bits);
+        }
+    }
+    data
+}
+
+fn ppm<T: Paint>(grid: &Grid2D<T>, scale: usize) -> Vec<u8> {
+    let (width, height) = (grid.width() * scale, grid.height() * scale);
+    let mut data = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+
+    for row in grid.rows() {
+        let pixels = row
+            .iter()
+            .flat_map(|cell| std::iter::repeat_n(cell.rgb(), scale))
+            .flatten()
+            .collect::<Vec<_>>();
+        for _ in 0..scale {
+            data.extend_from_slice(&pixels);
+        }
+    }
+    data
+}
+
+/// Writes numbered frames into a directory.
+#[derive(Debug)]
+pub struct Frames {
+    dir: PathBuf,
+    format: FrameFormat,
+    scale: usize,
+    written: usize,
+}
+
+impl Frames {
+    /// Writes frames into `dir`, creating it if needed.
+    pub fn new(dir: impl Into<PathBuf>, format: FrameFormat, scale: usize) -> io::Result<Frames> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Frames {
+            dir,
+            format,
+            scale: scale.max(1),
+            written: 0,
+        })
+    }
+
+    /// Writes `grid` as the next frame, returning the path it was written to.
+    pub fn push<T: Paint>(&mut self, grid: &Grid2D<T>) -> io::Result<PathBuf> {
+        let path = self.dir.join(format!(
+            "frame{:05}.{}",
+            self.written,
+            self.format.extension()
+        ));
+        fs::write(&path, self.format.encode(grid, self.scale))?;
+        self.written += 1;
+        Ok(path)
+    }
+
+    /// How many frames have been written so far.
+    pub fn written(&self) -> usize {
+        self.written
+    }
+}
+
+/// A day with a simulation or picture that can be written out as frames.
+pub trait Render: Solution {
+    /// Writes the frames for `part` from the parsed input.
+    fn render(
+        parsed: &Self::Parsed,
+        params: &Self::Params,
+        part: Part,
+        frames: &mut Frames,
+    ) -> io::Result<()>;
+}
+
+/// Why a day's frames couldn't be rendered.
+#[derive(Debug)]
+pub enum RenderError {
+    Config(ConfigError),
+    Parse(ParseError),
+    Io(io::Error),
+}
+
+impl Display for RenderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RenderError::Config(e) => write!(f, "invalid config: {}", e),
+            RenderError::Parse(e) => write!(f, "failed to parse input: {}", e),
+            RenderError::Io(e) => write!(f, "failed to write frame: {}", e),
+        }
+    }
+}
+
+impl Error for RenderError {}
+
+/// Parses a day's input and writes the frames for one part, see [`Render`].
+pub fn render<S: Render>(
+    part: Part,
+    input: &str,
+    config: &Config,
+    frames: &mut Frames,
+) -> Result<(), RenderError> {
+    let params = config.params::<S>().map_err(RenderError::Config)?;
+    let parsed = parse_input::<S>(input).map_err(RenderError::Parse)?;
+    S::render(&parsed, &params, part, frames).map_err(RenderError::Io)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FrameFormat, Frames, Paint};
+    use crate::grid::{Cell, Grid2D};
+    use std::fs;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Light {
+        On,
+        Off,
+    }
+
+    impl Cell for Light {
+        fn from_char(c: char) -> Option<Self> {
+            match c {
+                '#' => Some(Light::On),
+                '.' => Some(Light::Off),
+                _ => None,
+            }
+        }
+
+        fn to_char(&self) -> char {
+            match self {
+                Light::On => '#',
+                Light::Off => '.',
+            }
+        }
+    }
+
+    impl Paint for Light {
+        fn rgb(&self) -> [u8; 3] {
+            match self {
+                Light::On => [0, 0, 0],
+                Light::Off => [255, 255, 255],
+            }
+        }
+    }
+
+    fn lights() -> Grid2D<Light> {
+        Grid2D::parse("#.\n.#").unwrap()
+    }
+
+    #[test]
+    fn should_encode_ascii_as_the_input() {
+        assert_eq!(FrameFormat::Ascii.encode(&lights(), 3), b"#.\n.#\n");
+    }
+
+    #[test]
+    fn should_pack_bitmaps_and_scale_them() {
+        assert_eq!(
+            FrameFormat::Pbm.encode(&lights(), 1),
+            b"P4\n2 2\n\x80\x40".to_vec()
+        );
+        assert_eq!(
+            FrameFormat::Pbm.encode(&lights(), 5),
+            [
+                &b"P4\n10 10\n"[..],
+                &[0xf8, 0][..].repeat(5),
+                &[0x07, 0xc0][..].repeat(5)
+            ]
+            .concat()
+        );
+    }
+
+    #[test]
+    fn should_encode_pixmaps() {
+        let data = FrameFormat::Ppm.encode(&lights(), 1);
+        let (header, pixels) = data.split_at(11);
+
+        assert_eq!(header, b"P6\n2 2\n255\n");
+        assert_eq!(pixels, [0, 0, 0, 255, 255, 255, 255, 255, 255, 0, 0, 0]);
+    }
+
+    #[test]
+    fn should_number_frames() {
+        let dir = std::env::temp_dir().join(format!("aoc-frames-{}", std::process::id()));
+        let mut frames = Frames::new(&dir, FrameFormat::Ascii, 1).unwrap();
+
+        frames.push(&lights()).unwrap();
+        let second = frames.push(&lights()).unwrap();
+
+        assert_eq!(frames.written(), 2);
+        assert_eq!(second, dir.join("frame00001.txt"));
+        assert_eq!(fs::read_to_string(&second).unwrap(), "#.\n.#\n");
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
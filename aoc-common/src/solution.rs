@@ -0,0 +1,236 @@
+use crate::{
+    arith::checked, explain::explaining, input::normalise, progress::timed, ParseError, Part,
+    SolveError,
+};
+use serde::de::DeserializeOwned;
+use std::{
+    any::type_name,
+    fmt::Display,
+    process::exit,
+    time::{Duration, Instant},
+};
+
+/// A day's puzzle, split into parsing the input once and solving each part from the parsed form.
+pub trait Solution {
+    /// Day of the advent calendar this solves.
+    const DAY: u8;
+
+    /// Anything the puzzle fixes besides the input, like how many rounds to play, read from the
+    /// day's table in the [config](crate::config). The default is what the puzzle asks for.
+    type Params: Default + DeserializeOwned;
+    type Parsed;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    /// Parses the input, which [`parse_input`] has already normalised. Errors only need to blame
+    /// the offending text.
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError>;
+    /// Solves part 1, or says why the puzzle has no answer for this input, see
+    /// [`Unsolvable`](crate::Unsolvable).
+    fn part1(parsed: &Self::Parsed, params: &Self::Params) -> Result<Self::Answer1, SolveError>;
+    fn part2(parsed: &Self::Parsed, params: &Self::Params) -> Result<Self::Answer2, SolveError>;
+
+    /// Says what's wrong with parameters the puzzle can't be solved with, like a slope that never
+    /// goes down. [`Config::params`](crate::config::Config::params) rejects them with this.
+    fn check_params(_params: &Self::Params) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Parses a day's input once it's been [normalised](normalise), filling in where any error
+/// occurred.
+pub fn parse_input<S: Solution>(input: &str) -> Result<S::Parsed, ParseError> {
+    let input = normalise(input);
+    S::parse(&input).map_err(|e| e.locate(S::DAY, &input))
+}
+
+/// Solves one part of a day from its raw input with the puzzle's own parameters, formatting the
+/// answer.
+pub fn solve<S: Solution>(part: Part, input: &str) -> Result<String, SolveError> {
+    solve_with::<S>(part, input, &S::Params::default())
+}
+
+/// Solves one part of a day from its raw input with the given parameters, formatting the answer.
+pub fn solve_with<S: Solution>(
+    part: Part,
+    input: &str,
+    params: &S::Params,
+) -> Result<String, SolveError> {
+    let parsed = parse_input::<S>(input)?;
+    solve_part::<S>(part, &parsed, params)
+}
+
+/// Solves one part of a day from its parsed input, formatting the answer.
+fn solve_part<S: Solution>(
+    part: Part,
+    parsed: &S::Parsed,
+    params: &S::Params,
+) -> Result<String, SolveError> {
+    Ok(match part {
+        Part::One => S::part1(parsed, params)?.to_string(),
+        Part::Two => S::part2(parsed, params)?.to_string(),
+    })
+}
+
+/// The outcome of solving one part of a day, and how long it took.
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub part: Part,
+    /// Name of the type the part's answer is formatted from.
+    pub answer_type: &'static str,
+    pub answer: Result<String, SolveError>,
+    pub parse_time: Duration,
+    /// Zero when there's no answer.
+    pub solve_time: Duration,
+}
+
+/// Solves the given parts of a day from its raw input, parsing it only once and timing each
+/// stage. A part that runs out of time has that as its error, see [`progress`](crate::progress),
+/// as does one whose arithmetic overflows with the `checked` feature, see [`arith`](crate::arith).
+/// Each stage's notes say which stage it was, see [`explain`](crate::explain).
+pub fn run<S: Solution>(parts: &[Part], input: &str, params: &S::Params) -> Vec<Report> {
+    let start = Instant::now();
+    let parsed =
+        explaining(None, || checked(|| parse_input::<S>(input))).and_then(|parsed| Ok(parsed?));
+    let parse_time = start.elapsed();
+
+    parts
+        .iter()
+        .map(|&part| {
+            let answer_type = match part {
+                Part::One => type_name::<S::Answer1>(),
+                Part::Two => type_name::<S::Answer2>(),
+            };
+            let start = Instant::now();
+            let answer = parsed.as_ref().map_err(Clone::clone).and_then(|parsed| {
+                explaining(Some(part), || {
+                    timed::<S, _>(|| checked(|| solve_part::<S>(part, parsed, params)))
+                })??
+            });
+
+            Report {
+                part,
+                answer_type,
+                solve_time: if answer.is_ok() {
+                    start.elapsed()
+                } else {
+                    Duration::ZERO
+                },
+                answer,
+                parse_time,
+            }
+        })
+        .collect()
+}
+
+/// Prints both answers for a day with the puzzle's own parameters, exiting with a message if the
+/// input doesn't parse or a part has no answer.
+pub fn print_answers<S: Solution>(input: &str) {
+    let parsed = parse_input::<S>(input).unwrap_or_else(|e| {
+        eprintln!("failed to parse input: {}", e);
+        exit(1);
+    });
+    let params = S::Params::default();
+
+    for part in Part::ALL.iter() {
+        match solve_part::<S>(*part, &parsed, &params) {
+            Ok(answer) => println!("Part {}: {}", part, answer),
+            Err(e) => {
+                eprintln!("{}", e);
+                exit(1);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{run, solve, Solution};
+    use crate::{config::NoParams, ParseError, Part, SolveError, Unsolvable};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+
+        type Params = NoParams;
+        type Parsed = Vec<u32>;
+        type Answer1 = u32;
+        type Answer2 = String;
+
+        fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+            crate::parse::numbers(input)
+        }
+
+        fn part1(parsed: &Self::Parsed, _: &Self::Params) -> Result<u32, SolveError> {
+            Ok(parsed.iter().sum())
+        }
+
+        fn part2(parsed: &Self::Parsed, _: &Self::Params) -> Result<String, SolveError> {
+            match parsed.len() {
+                1 => Err(Unsolvable::new::<Sum>("expected more than one number").into()),
+                n => Ok(format!("{} numbers", n)),
+            }
+        }
+    }
+
+    #[test]
+    fn should_solve_each_part() {
+        assert_eq!(solve::<Sum>(Part::One, "1\n2\n3").unwrap(), "6");
+        assert_eq!(solve::<Sum>(Part::Two, "1\n2\n3").unwrap(), "3 numbers");
+    }
+
+    #[test]
+    fn should_solve_normalised_input() {
+        assert_eq!(
+            solve::<Sum>(Part::One, "1\r\n2 \r\n3\r\n\r\n").unwrap(),
+            "6"
+        );
+    }
+
+    #[test]
+    fn should_report_parse_errors() {
+        let err = solve::<Sum>(Part::One, "1\nx").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 0, line 2, column 1: invalid digit found in string (at \"x\")"
+        );
+    }
+
+    #[test]
+    fn should_report_each_part_from_one_parse() {
+        let reports = run::<Sum>(&Part::ALL, "1\n2\n3", &NoParams {});
+
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].answer.as_deref(), Ok("6"));
+        assert_eq!(reports[0].answer_type, "u32");
+        assert_eq!(reports[1].answer.as_deref(), Ok("3 numbers"));
+        assert_eq!(reports[0].parse_time, reports[1].parse_time);
+    }
+
+    #[test]
+    fn should_report_parts_without_an_answer() {
+        let reports = run::<Sum>(&Part::ALL, "5", &NoParams {});
+
+        assert_eq!(reports[0].answer.as_deref(), Ok("5"));
+        assert_eq!(
+            reports[1].answer,
+            Err(SolveError::Unsolvable(Unsolvable::new::<Sum>(
+                "expected more than one number"
+            )))
+        );
+        assert_eq!(
+            solve::<Sum>(Part::Two, "5").unwrap_err().to_string(),
+            "day 0: expected more than one number"
+        );
+    }
+
+    #[test]
+    fn should_report_parse_errors_for_every_part() {
+        let reports = run::<Sum>(&[Part::Two], "x", &NoParams {});
+
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].part, Part::Two);
+        assert!(reports[0].answer.is_err());
+    }
+}
@@ -0,0 +1,33 @@
+//! Property tests every day's parser should pass, so a day only has to say how to generate and
+//! print its input.
+//!
+//! ```ignore
+//! aoc_common::parses_what_it_prints!(expenses() => print, Day1::parse);
+//! ```
+//!
+//! The macro expands to a [proptest](https://docs.rs/proptest) test, so the day needs it as a
+//! dev-dependency. It can be used once per test module.
+
+use crate::{solve, Part, Solution, SolveError};
+
+/// The answers to `parts` of `S` from its raw input.
+pub fn answers<S: Solution>(parts: &[Part], input: &str) -> Vec<Result<String, SolveError>> {
+    parts.iter().map(|&p| solve::<S>(p, input)).collect()
+}
+
+/// A test that `$parse` gives back every value `$values` generates, after `$print` has written
+/// it out as input.
+#[macro_export]
+macro_rules! parses_what_it_prints {
+    ($values:expr => $print:expr, $parse:expr) => {
+        ::proptest::proptest! {
+            #[test]
+            fn parses_what_it_prints(value in $values) {
+                let (print, parse) = ($print, $parse);
+                let printed: String = print(&value);
+                let parsed = parse(printed.as_str()).map_err(|e| e.to_string());
+                ::proptest::prop_assert_eq!(parsed, Ok(value));
+            }
+        }
+    };
+}
@@ -0,0 +1,162 @@
+//! Checking that different ways of solving a part agree, so a faster one can stand in for a
+//! simpler one with confidence.
+
+use crate::{parse_input, ParseError, Part, Solution, SolveError};
+use std::fmt::{self, Display};
+
+/// One way of solving a part, named so a disagreement can say which it was.
+pub struct Variant<S: Solution + ?Sized, A> {
+    pub name: &'static str,
+    pub solve: fn(&S::Parsed, &S::Params) -> Result<A, SolveError>,
+}
+
+impl<S: Solution + ?Sized, A> Variant<S, A> {
+    pub fn new(
+        name: &'static str,
+        solve: fn(&S::Parsed, &S::Params) -> Result<A, SolveError>,
+    ) -> Self {
+        Variant { name, solve }
+    }
+}
+
+/// A day with other implementations of its parts, which have to give the same answers as
+/// [`Solution::part1`] and [`Solution::part2`].
+pub trait Variants: Solution {
+    fn part1_variants() -> Vec<Variant<Self, Self::Answer1>> {
+        Vec::new()
+    }
+
+    fn part2_variants() -> Vec<Variant<Self, Self::Answer2>> {
+        Vec::new()
+    }
+}
+
+/// A variant whose answer differs from the day's own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disagreement {
+    pub part: Part,
+    pub variant: &'static str,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "part {} `{}` gave {}, expected {}",
+            self.part, self.variant, self.actual, self.expected
+        )
+    }
+}
+
+/// Solves `parts` of a day's raw input every way it knows, returning the variants that disagree
+/// with the day's own answers. Answers are compared as they're printed, and a part without an
+/// answer by its error.
+pub fn disagreements<S: Variants>(
+    parts: &[Part],
+    input: &str,
+    params: &S::Params,
+) -> Result<Vec<Disagreement>, ParseError> {
+    fn compare<S: Solution, A: Display>(
+        part: Part,
+        parsed: &S::Parsed,
+        params: &S::Params,
+        reference: fn(&S::Parsed, &S::Params) -> Result<A, SolveError>,
+        variants: Vec<Variant<S, A>>,
+    ) -> Vec<Disagreement> {
+        let print = |answer: Result<A, SolveError>| match answer {
+            Ok(answer) => answer.to_string(),
+            Err(e) => e.to_string(),
+        };
+        let expected = print(reference(parsed, params));
+        variants
+            .into_iter()
+            .filter_map(|variant| {
+                let actual = print((variant.solve)(parsed, params));
+                (actual != expected).then(|| Disagreement {
+                    part,
+                    variant: variant.name,
+                    expected: expected.clone(),
+                    actual,
+                })
+            })
+            .collect()
+    }
+
+    let parsed = parse_input::<S>(input)?;
+    Ok(parts
+        .iter()
+        .flat_map(|&part| match part {
+            Part::One => compare(part, &parsed, params, S::part1, S::part1_variants()),
+            Part::Two => compare(part, &parsed, params, S::part2, S::part2_variants()),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{disagreements, Disagreement, Variant, Variants};
+    use crate::{config::NoParams, ParseError, Part, Solution, SolveError};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+
+        type Params = NoParams;
+        type Parsed = Vec<u32>;
+        type Answer1 = u32;
+        type Answer2 = u32;
+
+        fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+            crate::parse::numbers(input)
+        }
+
+        fn part1(parsed: &Self::Parsed, _: &Self::Params) -> Result<u32, SolveError> {
+            Ok(parsed.iter().sum())
+        }
+
+        fn part2(parsed: &Self::Parsed, _: &Self::Params) -> Result<u32, SolveError> {
+            Ok(parsed.iter().max().copied().unwrap_or(0))
+        }
+    }
+
+    impl Variants for Sum {
+        fn part1_variants() -> Vec<Variant<Self, u32>> {
+            vec![
+                Variant::new("loop", |parsed: &Vec<u32>, _| {
+                    let mut total = 0;
+                    for n in parsed {
+                        total += n;
+                    }
+                    Ok(total)
+                }),
+                Variant::new("last", |parsed: &Vec<u32>, _| Ok(*parsed.last().unwrap())),
+            ]
+        }
+    }
+
+    #[test]
+    fn should_report_variants_that_disagree() {
+        let found = disagreements::<Sum>(&Part::ALL, "1\n2\n3", &NoParams {}).unwrap();
+
+        assert_eq!(
+            found,
+            vec![Disagreement {
+                part: Part::One,
+                variant: "last",
+                expected: String::from("6"),
+                actual: String::from("3"),
+            }]
+        );
+        assert_eq!(found[0].to_string(), "part 1 `last` gave 3, expected 6");
+    }
+
+    #[test]
+    fn should_only_check_the_given_parts() {
+        let found = disagreements::<Sum>(&[Part::Two], "1\n2\n3", &NoParams {}).unwrap();
+        assert!(found.is_empty());
+        assert!(disagreements::<Sum>(&[Part::One], "x", &NoParams {}).is_err());
+    }
+}
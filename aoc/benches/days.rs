@@ -0,0 +1,75 @@
+//! Benchmarks the parse, part 1 and part 2 stages of every day against the checked-in examples.
+//!
+//! Run a single day with e.g. `cargo bench -p aoc -- day15/`.
+
+use aoc_common::{config::Config, parse_input, Solution};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::path::Path;
+
+macro_rules! example {
+    ($day:literal) => {
+        include_str!(concat!("../../examples/day", $day, "/example.txt"))
+    };
+}
+
+/// The parameters the example for `S` is set with in its `example.toml`, if it has one.
+fn example_params<S: Solution>() -> S::Params {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join(format!("../examples/day{}/example.toml", S::DAY));
+    Config::load(&path)
+        .and_then(|config| config.params::<S>())
+        .unwrap_or_else(|e| panic!("{}: {}", path.display(), e))
+}
+
+/// Criterion won't take fewer samples than this, which is plenty for the multi-second parts.
+const FEW_SAMPLES: usize = 10;
+
+fn bench_day<S: Solution>(c: &mut Criterion, input: &str, sample_size: usize) {
+    let mut group = c.benchmark_group(format!("day{}", S::DAY));
+    group.sample_size(sample_size);
+
+    // through the same normalising as `aoc run`, so the examples parse as they do there
+    group.bench_function("parse", |b| b.iter(|| parse_input::<S>(black_box(input))));
+
+    let parsed = parse_input::<S>(input).expect("examples should parse");
+    let params = example_params::<S>();
+    group.bench_function("part1", |b| {
+        b.iter(|| S::part1(black_box(&parsed), &params))
+    });
+    group.bench_function("part2", |b| {
+        b.iter(|| S::part2(black_box(&parsed), &params))
+    });
+
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<day1::Day1>(c, example!(1), 100);
+    bench_day::<day2::Day2>(c, example!(2), 100);
+    bench_day::<day3::Day3>(c, example!(3), 100);
+    bench_day::<day4::Day4>(c, example!(4), 100);
+    bench_day::<day5::Day5>(c, example!(5), 100);
+    bench_day::<day6::Day6>(c, example!(6), 100);
+    bench_day::<day7::Day7>(c, example!(7), 100);
+    bench_day::<day8::Day8>(c, example!(8), 100);
+    bench_day::<day9::Day9>(c, example!(9), 100);
+    bench_day::<day10::Day10>(c, example!(10), 100);
+    bench_day::<day11::Day11>(c, example!(11), 100);
+    bench_day::<day12::Day12>(c, example!(12), 100);
+    bench_day::<day13::Day13>(c, example!(13), 100);
+    bench_day::<day14::Day14>(c, example!(14), 100);
+    bench_day::<day15::Day15>(c, example!(15), FEW_SAMPLES);
+    bench_day::<day16::Day16>(c, example!(16), 100);
+    bench_day::<day17::Day17>(c, example!(17), FEW_SAMPLES);
+    bench_day::<day18::Day18>(c, example!(18), 100);
+    bench_day::<day19::Day19>(c, example!(19), 100);
+    bench_day::<day20::Day20>(c, example!(20), FEW_SAMPLES);
+    bench_day::<day21::Day21>(c, example!(21), 100);
+    bench_day::<day22::Day22>(c, example!(22), 100);
+    bench_day::<day23::Day23>(c, example!(23), FEW_SAMPLES);
+    bench_day::<day24::Day24>(c, example!(24), FEW_SAMPLES);
+    bench_day::<day25::Day25>(c, example!(25), 100);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,132 @@
+//! The golden answers file: one known-good answer per day, part and input.
+//!
+//! Each line holds a day, a part, a hash of the input and the answer, separated by tabs. Lines
+//! starting with `#` are ignored.
+
+use aoc_common::{input::normalise, Part};
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{self, ErrorKind},
+    path::Path,
+};
+
+/// The day and part an answer is for, and the hash of the input it was computed from.
+pub type Key = (u8, Part, String);
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers(BTreeMap<Key, String>);
+
+impl Answers {
+    /// Reads the answers file, treating a missing file as having no answers yet.
+    pub fn load(path: &Path) -> io::Result<Answers> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Answers::default()),
+            Err(e) => return Err(e),
+        };
+
+        contents
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| !l.trim().is_empty() && !l.starts_with('#'))
+            .map(|(idx, l)| {
+                parse_line(l).ok_or_else(|| {
+                    let msg = format!("{}: malformed answer on line {}", path.display(), idx + 1);
+                    io::Error::new(ErrorKind::InvalidData, msg)
+                })
+            })
+            .collect::<io::Result<_>>()
+            .map(Answers)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut contents = String::from("# day\tpart\tinput hash\tanswer\n");
+        for ((day, part, hash), answer) in self.0.iter() {
+            contents += &format!("{}\t{}\t{}\t{}\n", day, part, hash, answer);
+        }
+        fs::write(path, contents)
+    }
+
+    pub fn get(&self, key: &Key) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+
+    /// Whether any answers are recorded for `day`, whatever the input.
+    pub fn has_day(&self, day: u8) -> bool {
+        self.0.keys().any(|&(d, _, _)| d == day)
+    }
+
+    /// Records an answer, returning the one it replaced.
+    pub fn insert(&mut self, key: Key, answer: String) -> Option<String> {
+        self.0.insert(key, answer)
+    }
+}
+
+fn parse_line(line: &str) -> Option<(Key, String)> {
+    let mut fields = line.splitn(4, '\t');
+    let day = fields.next()?.parse().ok()?;
+    let part = fields.next()?.parse().ok()?;
+    let hash = fields.next()?.to_owned();
+    let answer = fields.next()?.to_owned();
+
+    Some(((day, part, hash), answer))
+}
+
+/// The hash an input's answers are recorded under: that of the text the days actually parse, so
+/// line endings and trailing whitespace don't change it.
+pub fn hash_input(raw: &str) -> String {
+    hash(&normalise(raw))
+}
+
+/// A stable 64 bit FNV-1a hash of an input, so answers survive toolchain upgrades.
+pub fn hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{:016x}", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hash, hash_input, Answers};
+    use aoc_common::Part;
+    use std::{env, fs, process};
+
+    #[test]
+    fn should_hash_inputs_stably() {
+        assert_eq!(hash(""), "cbf29ce484222325");
+        assert_eq!(hash("a"), "af63dc4c8601ec8c");
+        assert_ne!(hash("1\n2\n"), hash("1\n2"));
+    }
+
+    #[test]
+    fn should_hash_inputs_as_they_are_parsed() {
+        assert_eq!(hash_input("1\r\n2\r\n"), hash("1\n2\n"));
+        assert_eq!(hash_input("1\n2"), hash("1\n2\n"));
+        assert_eq!(hash_input("1 \n2\n\n"), hash("1\n2\n"));
+    }
+
+    #[test]
+    fn should_round_trip_through_the_file() {
+        let path = env::temp_dir().join(format!("aoc-answers-{}.txt", process::id()));
+        let mut answers = Answers::default();
+        answers.insert((3, Part::Two, hash("..#")), "336".into());
+        answers.insert((21, Part::Two, hash("abc")), "mxmxvkd,sqjhc".into());
+
+        answers.save(&path).unwrap();
+        let loaded = Answers::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, answers);
+        assert_eq!(loaded.get(&(3, Part::Two, hash("..#"))), Some("336"));
+        assert!(loaded.has_day(21));
+        assert!(!loaded.has_day(4));
+    }
+
+    #[test]
+    fn should_treat_a_missing_file_as_empty() {
+        let path = env::temp_dir().join("aoc-answers-that-do-not-exist.txt");
+        assert_eq!(Answers::load(&path).unwrap(), Answers::default());
+    }
+}
@@ -0,0 +1,97 @@
+use aoc_common::config::{Config, ConfigError};
+use aoc_common::render::{render, Frames, RenderError};
+use aoc_common::variants::{disagreements, Disagreement, Variants};
+use aoc_common::{run, Part, Report, Solution};
+use std::path::{Path, PathBuf};
+
+/// Solves the given parts of a day from its raw input with its parameters from the config, see
+/// [`aoc_common::run`].
+pub type Runner = fn(&[Part], &str, &Config) -> Result<Vec<Report>, ConfigError>;
+
+fn configured<S: Solution>(
+    parts: &[Part],
+    input: &str,
+    config: &Config,
+) -> Result<Vec<Report>, ConfigError> {
+    Ok(run::<S>(parts, input, &config.params::<S>()?))
+}
+
+const DAYS: [Runner; 25] = [
+    configured::<day1::Day1>,
+    configured::<day2::Day2>,
+    configured::<day3::Day3>,
+    configured::<day4::Day4>,
+    configured::<day5::Day5>,
+    configured::<day6::Day6>,
+    configured::<day7::Day7>,
+    configured::<day8::Day8>,
+    configured::<day9::Day9>,
+    configured::<day10::Day10>,
+    configured::<day11::Day11>,
+    configured::<day12::Day12>,
+    configured::<day13::Day13>,
+    configured::<day14::Day14>,
+    configured::<day15::Day15>,
+    configured::<day16::Day16>,
+    configured::<day17::Day17>,
+    configured::<day18::Day18>,
+    configured::<day19::Day19>,
+    configured::<day20::Day20>,
+    configured::<day21::Day21>,
+    configured::<day22::Day22>,
+    configured::<day23::Day23>,
+    configured::<day24::Day24>,
+    configured::<day25::Day25>,
+];
+
+pub fn all() -> impl Iterator<Item = (u8, Runner)> {
+    (1..).zip(DAYS.iter().copied())
+}
+
+pub fn get(day: u8) -> Option<Runner> {
+    DAYS.get(usize::from(day).checked_sub(1)?).copied()
+}
+
+/// Writes the frames for one part of a day from its raw input, see [`aoc_common::render`].
+pub type Renderer = fn(Part, &str, &Config, &mut Frames) -> Result<(), RenderError>;
+
+/// The days with a simulation or picture worth drawing.
+pub fn renderer(day: u8) -> Option<Renderer> {
+    match day {
+        11 => Some(render::<day11::Day11>),
+        17 => Some(render::<day17::Day17>),
+        20 => Some(render::<day20::Day20>),
+        24 => Some(render::<day24::Day24>),
+        _ => None,
+    }
+}
+
+/// Solves the given parts of a day's raw input every way it knows, returning the implementations
+/// that disagree, or why it couldn't.
+pub type Checker = fn(&[Part], &str, &Config) -> Result<Vec<Disagreement>, String>;
+
+fn check<S: Variants>(
+    parts: &[Part],
+    input: &str,
+    config: &Config,
+) -> Result<Vec<Disagreement>, String> {
+    let params = config.params::<S>().map_err(|e| e.to_string())?;
+    disagreements::<S>(parts, input, &params).map_err(|e| e.to_string())
+}
+
+/// The days with more than one implementation of a part, see [`Variants`].
+pub fn checker(day: u8) -> Option<Checker> {
+    match day {
+        9 => Some(check::<day9::Day9>),
+        10 => Some(check::<day10::Day10>),
+        14 => Some(check::<day14::Day14>),
+        15 => Some(check::<day15::Day15>),
+        23 => Some(check::<day23::Day23>),
+        _ => None,
+    }
+}
+
+/// Where a day's puzzle input lives when no path is given explicitly.
+pub fn default_input(inputs: &Path, day: u8) -> PathBuf {
+    inputs.join(format!("day{}", day)).join("src").join("input")
+}
@@ -0,0 +1,45 @@
+//! The puzzles' worked examples, kept as `examples/dayN/<name>.txt`.
+//!
+//! An example can have a `<name>.expected` sidecar with a line per part holding the part number
+//! and the expected answer separated by a tab. Blank lines and lines starting with `#` are
+//! ignored, and parts without a line aren't checked. An example whose puzzle parameters differ
+//! from the real puzzle's sets them in a `<name>.toml` sidecar, laid out like `aoc.toml`.
+
+use aoc_common::Part;
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// The directory the examples are kept in.
+pub const EXAMPLES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../examples");
+
+/// The example inputs for `day` in `examples`, in order of name.
+pub fn inputs(examples: &Path, day: u8) -> Vec<PathBuf> {
+    let mut inputs = match fs::read_dir(examples.join(format!("day{}", day))) {
+        Ok(entries) => entries
+            .filter_map(|entry| Some(entry.ok()?.path()))
+            .filter(|path| path.extension() == Some("txt".as_ref()))
+            .collect::<Vec<_>>(),
+        Err(_) => return vec![],
+    };
+    inputs.sort();
+    inputs
+}
+
+/// Reads the answers in the `.expected` sidecar at `path`.
+pub fn expected_answers(path: &Path) -> io::Result<Vec<(Part, String)>> {
+    let invalid = |msg: String| io::Error::new(io::ErrorKind::InvalidData, msg);
+
+    fs::read_to_string(path)?
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let (part, answer) = line
+                .split_once('\t')
+                .ok_or_else(|| invalid(format!("expected `part<TAB>answer`, found {:?}", line)))?;
+            let part = part.parse().map_err(invalid)?;
+            Ok((part, answer.to_owned()))
+        })
+        .collect()
+}
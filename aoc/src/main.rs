@@ -0,0 +1,583 @@
+mod answers;
+mod days;
+mod examples;
+#[cfg(feature = "serve")]
+mod serve;
+mod watch;
+
+use answers::Answers;
+use aoc_common::config::{Config, ConfigError};
+use aoc_common::explain;
+use aoc_common::progress::{self, Monitor};
+use aoc_common::render::{FrameFormat, Frames, RenderError};
+use aoc_common::{input::Source, Part, Report, SolveError};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use serde_json::{json, Value};
+use std::{
+    ffi::OsString,
+    fs::read_to_string,
+    io::{self, IsTerminal},
+    path::{Path, PathBuf},
+    process::exit,
+    time::Duration,
+};
+
+/// Runs the Advent of Code 2020 solutions.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day, or every day with --all
+    Run(RunArgs),
+    /// Check every day's answers, with the puzzles' own parameters, against the recorded ones
+    Verify(VerifyArgs),
+    /// Write a day's simulation out as numbered frames (days 11, 17, 20 and 24)
+    Render(RenderArgs),
+    /// Solve a day and its examples again whenever they or the config change
+    Watch(WatchArgs),
+    /// Answer POST /day/{n}/part/{p} and POST /day/{n} requests with the puzzle input as the body
+    #[cfg(feature = "serve")]
+    Serve(ServeArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to solve (1-25)
+    #[arg(long, required_unless_present = "all", value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Only solve this part (1 or 2)
+    #[arg(long, requires = "day")]
+    part: Option<Part>,
+
+    /// Puzzle input file or - for stdin, defaults to $AOC_INPUT then dayN/src/input
+    #[arg(long, requires = "day")]
+    input: Option<OsString>,
+
+    /// Solve every day and print a table of the answers
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+
+    /// Directory containing the dayN crates, used to find default inputs
+    #[arg(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/.."))]
+    inputs: PathBuf,
+
+    /// How to print the answers
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Give up on a part after this many seconds (days 15, 22, 23 and 24)
+    #[arg(long, value_name = "SECONDS", value_parser = seconds)]
+    timeout: Option<Duration>,
+
+    /// Print the steps each part took to its answer to stderr (days 7, 16, 20 and 21)
+    #[arg(long)]
+    explain: bool,
+
+    #[command(flatten)]
+    config: ConfigArgs,
+}
+
+#[derive(Args)]
+struct ConfigArgs {
+    /// TOML file of puzzle parameters, defaults to aoc.toml next to the dayN crates if it exists
+    #[arg(long)]
+    config: Option<PathBuf>,
+
+    /// Override a puzzle parameter, e.g. --set day15.part2_turns=1000
+    #[arg(long = "set", value_name = "dayN.NAME=VALUE")]
+    overrides: Vec<String>,
+}
+
+impl ConfigArgs {
+    fn path(&self) -> &Path {
+        let default = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../aoc.toml"));
+        self.config.as_deref().unwrap_or(default)
+    }
+
+    /// Loads the config and applies the overrides.
+    fn try_load(&self) -> Result<Config, String> {
+        let path = self.path();
+        if self.config.is_some() && !path.exists() {
+            return Err(format!("config file {} doesn't exist", path.display()));
+        }
+
+        let mut config = Config::load(path).map_err(|e| format!("invalid config: {}", e))?;
+        for assignment in self.overrides.iter() {
+            config
+                .set(assignment)
+                .map_err(|e| format!("invalid --set: {}", e))?;
+        }
+        Ok(config)
+    }
+
+    /// Loads the config and applies the overrides, exiting with a message if either is invalid.
+    fn load(&self) -> Config {
+        self.try_load().unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            exit(1);
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Prose for a single day, a table for --all
+    Text,
+    /// An array with the answer, its type, timings and any error for each day and part
+    Json,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Only verify this day (1-25)
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Record this run's answers as the correct ones instead of checking them
+    #[arg(long)]
+    record: bool,
+
+    /// Also check that every implementation of a part gives the same answer (days 9, 10, 14, 15
+    /// and 23)
+    #[arg(long)]
+    variants: bool,
+
+    /// File holding the recorded answers
+    #[arg(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt"))]
+    answers: PathBuf,
+
+    /// Directory containing the dayN crates, used to find default inputs
+    #[arg(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/.."))]
+    inputs: PathBuf,
+}
+
+#[derive(Args)]
+struct RenderArgs {
+    /// Day to draw
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Part whose simulation to draw (1 or 2)
+    #[arg(long, default_value = "1")]
+    part: Part,
+
+    /// Puzzle input file or - for stdin, defaults to $AOC_INPUT then dayN/src/input
+    #[arg(long)]
+    input: Option<OsString>,
+
+    /// Directory containing the dayN crates, used to find default inputs
+    #[arg(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/.."))]
+    inputs: PathBuf,
+
+    /// Directory to write the frames into, created if needed
+    #[arg(long, default_value = "frames")]
+    out: PathBuf,
+
+    /// File format of each frame
+    #[arg(long, value_enum, default_value_t = FrameKind::Ascii)]
+    frame_format: FrameKind,
+
+    /// Width in pixels of each cell in image frames
+    #[arg(long, default_value_t = 4)]
+    scale: usize,
+
+    #[command(flatten)]
+    config: ConfigArgs,
+}
+
+#[derive(Args)]
+struct WatchArgs {
+    /// Day to solve
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Puzzle input file, defaults to $AOC_INPUT then dayN/src/input
+    #[arg(long)]
+    input: Option<OsString>,
+
+    /// Directory containing the dayN crates, used to find default inputs
+    #[arg(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/.."))]
+    inputs: PathBuf,
+
+    /// Directory containing the dayN example directories
+    #[arg(long, default_value = examples::EXAMPLES)]
+    examples: PathBuf,
+
+    #[command(flatten)]
+    config: ConfigArgs,
+}
+
+#[cfg(feature = "serve")]
+#[derive(Args)]
+struct ServeArgs {
+    /// Address to listen on
+    #[arg(long, default_value = "127.0.0.1:2020")]
+    address: String,
+
+    /// Give up on a part after this many seconds (days 15, 22, 23 and 24)
+    #[arg(long, value_name = "SECONDS", value_parser = seconds)]
+    timeout: Option<Duration>,
+
+    #[command(flatten)]
+    config: ConfigArgs,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum FrameKind {
+    /// Text, drawn with the puzzle's own characters
+    Ascii,
+    /// Black and white portable bitmaps
+    Pbm,
+    /// Colour portable pixmaps
+    Ppm,
+}
+
+impl From<FrameKind> for FrameFormat {
+    fn from(kind: FrameKind) -> FrameFormat {
+        match kind {
+            FrameKind::Ascii => FrameFormat::Ascii,
+            FrameKind::Pbm => FrameFormat::Pbm,
+            FrameKind::Ppm => FrameFormat::Ppm,
+        }
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run(args) => {
+            progress::monitor(Monitor {
+                bar: args.format == Format::Text && io::stderr().is_terminal(),
+                timeout: args.timeout,
+            });
+            if args.explain {
+                explain::explain_to(Some(Box::new(io::stderr())));
+            }
+            match args.day {
+                Some(day) => run_day(day, &args),
+                None => run_all(&args),
+            }
+        }
+        Command::Verify(args) => {
+            progress::monitor(Monitor {
+                bar: io::stderr().is_terminal(),
+                timeout: None,
+            });
+            verify(&args)
+        }
+        Command::Render(args) => render_day(&args),
+        Command::Watch(args) => {
+            progress::monitor(Monitor {
+                bar: io::stderr().is_terminal(),
+                timeout: None,
+            });
+            watch_day(&args)
+        }
+        #[cfg(feature = "serve")]
+        Command::Serve(args) => {
+            progress::monitor(Monitor {
+                bar: false,
+                timeout: args.timeout,
+            });
+            if let Err(e) = serve::serve(&args.address, &args.config.load()) {
+                eprintln!("failed to serve on {}: {}", args.address, e);
+                exit(1);
+            }
+        }
+    }
+}
+
+fn watch_day(args: &WatchArgs) {
+    let input = match Source::resolve(
+        args.input.clone(),
+        &days::default_input(&args.inputs, args.day),
+    ) {
+        Source::File(path) => path,
+        Source::Stdin => {
+            eprintln!("can't watch stdin, pass an input file instead");
+            exit(1);
+        }
+    };
+    // events name the paths the same way the watched directories were given
+    let absolute = |path: &Path| std::path::absolute(path).unwrap_or_else(|_| path.to_owned());
+
+    let watch = watch::Watch {
+        day: args.day,
+        run: days::get(args.day).expect("day is validated by clap"),
+        input: absolute(&input),
+        examples: absolute(&args.examples),
+        config: absolute(args.config.path()),
+        load_config: &|| args.config.try_load(),
+    };
+    if let Err(e) = watch.run() {
+        eprintln!("failed to watch for changes: {}", e);
+        exit(1);
+    }
+}
+
+fn render_day(args: &RenderArgs) {
+    let render = days::renderer(args.day).unwrap_or_else(|| {
+        eprintln!("day {} has nothing to render", args.day);
+        exit(1);
+    });
+    let source = Source::resolve(
+        args.input.clone(),
+        &days::default_input(&args.inputs, args.day),
+    );
+    let input = source.read().unwrap_or_else(|e| {
+        eprintln!("failed to read input from {}: {}", source, e);
+        exit(1);
+    });
+
+    let result = Frames::new(&args.out, args.frame_format.into(), args.scale)
+        .map_err(RenderError::Io)
+        .and_then(|mut frames| {
+            render(args.part, &input, &args.config.load(), &mut frames)?;
+            Ok(frames.written())
+        });
+    match result {
+        Ok(written) => println!("wrote {} frames to {}", written, args.out.display()),
+        Err(e) => {
+            eprintln!("{}", e);
+            exit(1);
+        }
+    }
+}
+
+fn run_day(day: u8, args: &RunArgs) {
+    let run = days::get(day).expect("day is validated by clap");
+    let source = Source::resolve(args.input.clone(), &days::default_input(&args.inputs, day));
+
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("failed to read input from {}: {}", source, e);
+            exit(1);
+        }
+    };
+
+    let parts = args
+        .part
+        .as_ref()
+        .map_or(&Part::ALL[..], std::slice::from_ref);
+    let reports = valid_config(run(parts, &input, &args.config.load()));
+
+    if args.format == Format::Json {
+        print_json(reports.iter().map(|r| report_json(day, r)).collect());
+        if reports.iter().any(|r| r.answer.is_err()) {
+            exit(1);
+        }
+        return;
+    }
+
+    for report in reports {
+        let answer = report.answer.unwrap_or_else(|e| {
+            match e {
+                SolveError::Parse(e) => eprintln!("failed to parse input: {}", e),
+                e => eprintln!("{}", e),
+            }
+            exit(1);
+        });
+        match args.part {
+            Some(_) => println!("{}", answer),
+            None => println!("Part {}: {}", report.part, answer),
+        }
+    }
+}
+
+fn run_all(args: &RunArgs) {
+    let mut rows = vec![[
+        String::from("Day"),
+        String::from("Part 1"),
+        String::from("Part 2"),
+    ]];
+    let mut records = Vec::new();
+    let config = args.config.load();
+
+    for (day, run) in days::all() {
+        let path = days::default_input(&args.inputs, day);
+        let reports = match read_to_string(&path) {
+            Ok(input) => valid_config(run(&Part::ALL, &input, &config)),
+            Err(_) => {
+                for &part in Part::ALL.iter() {
+                    records.push(missing_input_json(day, part));
+                }
+                rows.push([day.to_string(), "no input".into(), "no input".into()]);
+                continue;
+            }
+        };
+
+        records.extend(reports.iter().map(|r| report_json(day, r)));
+        let cell = |r: &Report| match &r.answer {
+            Ok(answer) => answer.clone(),
+            Err(e) => format!("error: {}", e),
+        };
+        rows.push([day.to_string(), cell(&reports[0]), cell(&reports[1])]);
+    }
+
+    match args.format {
+        Format::Text => print_table(&rows),
+        Format::Json => print_json(records),
+    }
+}
+
+fn seconds(arg: &str) -> Result<Duration, String> {
+    let seconds = arg.parse::<f64>().map_err(|e| e.to_string())?;
+    Duration::try_from_secs_f64(seconds).map_err(|e| e.to_string())
+}
+
+/// Unwraps a day's reports, exiting with a message if its parameters are invalid.
+fn valid_config<T>(result: Result<T, ConfigError>) -> T {
+    result.unwrap_or_else(|e| {
+        eprintln!("invalid config: {}", e);
+        exit(1);
+    })
+}
+
+fn report_json(day: u8, report: &Report) -> Value {
+    let millis = |d: std::time::Duration| d.as_secs_f64() * 1000.0;
+    let (answer, solve_ms, error) = match &report.answer {
+        Ok(answer) => (json!(answer), json!(millis(report.solve_time)), Value::Null),
+        Err(e) => (Value::Null, Value::Null, json!(e.to_string())),
+    };
+
+    json!({
+        "day": day,
+        "part": report.part.number(),
+        "answer": answer,
+        "type": report.answer_type,
+        "parse_ms": millis(report.parse_time),
+        "solve_ms": solve_ms,
+        "error": error,
+    })
+}
+
+fn missing_input_json(day: u8, part: Part) -> Value {
+    json!({
+        "day": day,
+        "part": part.number(),
+        "answer": null,
+        "type": null,
+        "parse_ms": null,
+        "solve_ms": null,
+        "error": "no input",
+    })
+}
+
+fn print_json(records: Vec<Value>) {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&Value::Array(records)).expect("JSON values always serialize")
+    );
+}
+
+fn verify(args: &VerifyArgs) {
+    let mut answers = Answers::load(&args.answers).unwrap_or_else(|e| {
+        eprintln!("failed to load answers: {}", e);
+        exit(1);
+    });
+    let mut failed = false;
+
+    let days = days::all().filter(|(day, _)| args.day.is_none_or(|d| d == *day));
+    for (day, run) in days {
+        let input = match read_to_string(days::default_input(&args.inputs, day)) {
+            Ok(input) => input,
+            Err(_) => {
+                println!("day {:>2}: no input", day);
+                // answers were recorded against an input that's since gone missing
+                failed |= answers.has_day(day);
+                continue;
+            }
+        };
+        let hash = answers::hash_input(&input);
+
+        // the recorded answers are to the puzzles as set, so aoc.toml doesn't apply here
+        let reports = valid_config(run(&Part::ALL, &input, &Config::default()));
+        for report in reports {
+            let part = report.part;
+            let label = format!("day {:>2} part {}", day, part);
+            let actual = match report.answer {
+                Ok(actual) => actual,
+                Err(e) => {
+                    println!("{}: {}", label, e);
+                    failed = true;
+                    continue;
+                }
+            };
+
+            let key = (day, part, hash.clone());
+            match answers.get(&key) {
+                Some(expected) if expected == actual => println!("{}: ok", label),
+                Some(expected) if !args.record => {
+                    println!("{}: mismatch for input {}", label, hash);
+                    println!("  - {}", expected);
+                    println!("  + {}", actual);
+                    failed = true;
+                }
+                _ if args.record => {
+                    println!("{}: recorded {}", label, actual);
+                    answers.insert(key, actual);
+                }
+                _ => println!("{}: no recorded answer for input {}", label, hash),
+            }
+        }
+
+        if let Some(check) = days::checker(day).filter(|_| args.variants) {
+            match check(&Part::ALL, &input, &Config::default()) {
+                Ok(found) if found.is_empty() => println!("day {:>2} variants: ok", day),
+                Ok(found) => {
+                    for disagreement in found {
+                        println!("day {:>2}: {}", day, disagreement);
+                    }
+                    failed = true;
+                }
+                Err(e) => {
+                    println!("day {:>2} variants: {}", day, e);
+                    failed = true;
+                }
+            }
+        }
+    }
+
+    if args.record {
+        if let Err(e) = answers.save(&args.answers) {
+            eprintln!("failed to save answers: {}", e);
+            exit(1);
+        }
+    }
+    if failed {
+        exit(1);
+    }
+}
+
+fn print_table(rows: &[[String; 3]]) {
+    let widths = (0..3)
+        .map(|col| rows.iter().map(|r| r[col].len()).max().unwrap_or(0))
+        .collect::<Vec<_>>();
+
+    for (idx, row) in rows.iter().enumerate() {
+        println!(
+            "{:>w0$} | {:<w1$} | {}",
+            row[0],
+            row[1],
+            row[2],
+            w0 = widths[0],
+            w1 = widths[1]
+        );
+        if idx == 0 {
+            println!(
+                "{}-+-{}-+-{}",
+                "-".repeat(widths[0]),
+                "-".repeat(widths[1]),
+                "-".repeat(widths[2])
+            );
+        }
+    }
+}
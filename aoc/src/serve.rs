@@ -0,0 +1,151 @@
+//! The solvers over HTTP, for tools that would rather not shell out.
+//!
+//! `POST /day/{n}/part/{p}` solves one part of day `n` from the puzzle input in the request
+//! body, and `POST /day/{n}` solves both parts. Answers come back as the objects
+//! `aoc run --format json` prints, one for a single part or an array of both, with a 422 status
+//! if any part has an error instead of an answer.
+
+use crate::{days, report_json};
+use aoc_common::{config::Config, Part};
+use serde_json::{json, Value};
+use std::panic;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+/// Answers requests on `address` until the server fails.
+pub fn serve(address: &str, config: &Config) -> Result<(), String> {
+    let server = Server::http(address).map_err(|e| e.to_string())?;
+    println!("listening on http://{}", address);
+
+    for mut request in server.incoming_requests() {
+        let mut body = String::new();
+        let (status, value) = match request.as_reader().read_to_string(&mut body) {
+            Ok(_) => respond(request.method(), request.url(), &body, config),
+            Err(e) => (400, error(format!("failed to read the body: {}", e))),
+        };
+        reply(request, status, &value);
+    }
+    Ok(())
+}
+
+fn reply(request: Request, status: u16, value: &Value) {
+    let json =
+        Header::from_bytes("Content-Type", "application/json").expect("the header is valid ASCII");
+    let response = Response::from_string(value.to_string())
+        .with_status_code(status)
+        .with_header(json);
+    if let Err(e) = request.respond(response) {
+        eprintln!("failed to respond: {}", e);
+    }
+}
+
+fn error(message: impl Into<String>) -> Value {
+    json!({ "error": message.into() })
+}
+
+/// The status code and JSON body answering a request.
+fn respond(method: &Method, url: &str, body: &str, config: &Config) -> (u16, Value) {
+    let (day, part) = match route(url) {
+        Some(route) => route,
+        None => return (404, error("expected /day/{n} or /day/{n}/part/{p}")),
+    };
+    let run = match days::get(day) {
+        Some(run) => run,
+        None => return (404, error(format!("there's no day {}", day))),
+    };
+    if *method != Method::Post {
+        return (405, error("expected a POST with the puzzle input"));
+    }
+
+    let parts = part.as_ref().map_or(&Part::ALL[..], std::slice::from_ref);
+    // one bad input shouldn't take the server down with it
+    let reports = match panic::catch_unwind(|| run(parts, body, config)) {
+        Ok(Ok(reports)) => reports,
+        Ok(Err(e)) => return (500, error(format!("invalid config: {}", e))),
+        Err(payload) => {
+            let message = (payload.downcast_ref::<String>().map(String::as_str))
+                .or_else(|| payload.downcast_ref::<&str>().copied())
+                .unwrap_or("no message");
+            return (500, error(format!("day {} panicked: {}", day, message)));
+        }
+    };
+
+    let status = match reports.iter().all(|r| r.answer.is_ok()) {
+        true => 200,
+        false => 422,
+    };
+    let mut values = reports
+        .iter()
+        .map(|r| report_json(day, r))
+        .collect::<Vec<_>>();
+    match part {
+        Some(_) => (status, values.remove(0)),
+        None => (status, Value::Array(values)),
+    }
+}
+
+/// The day and, if it's just one, the part a request is for.
+fn route(url: &str) -> Option<(u8, Option<Part>)> {
+    let path = url.split('?').next()?;
+    let segments = path.trim_matches('/').split('/').collect::<Vec<_>>();
+    match segments[..] {
+        ["day", day] => Some((day.parse().ok()?, None)),
+        ["day", day, "part", part] => Some((day.parse().ok()?, Some(part.parse().ok()?))),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{respond, route};
+    use aoc_common::{config::Config, Part};
+    use tiny_http::Method;
+
+    const EXPENSES: &str = "1721\n979\n366\n299\n675\n1456\n";
+
+    #[test]
+    fn should_route_days_and_parts() {
+        assert_eq!(route("/day/3"), Some((3, None)));
+        assert_eq!(route("/day/25/part/2?pretty"), Some((25, Some(Part::Two))));
+        assert_eq!(route("/day/3/part/3"), None);
+        assert_eq!(route("/days/3"), None);
+    }
+
+    #[test]
+    fn should_answer_a_part() {
+        let (status, value) = respond(&Method::Post, "/day/1/part/1", EXPENSES, &Config::default());
+
+        assert_eq!(status, 200);
+        assert_eq!(value["answer"], "514579");
+        assert_eq!(value["part"], 1);
+        assert!(value["solve_ms"].is_f64());
+    }
+
+    #[test]
+    fn should_answer_both_parts() {
+        let (status, value) = respond(&Method::Post, "/day/1", EXPENSES, &Config::default());
+
+        assert_eq!(status, 200);
+        assert_eq!(value[0]["answer"], "514579");
+        assert_eq!(value[1]["answer"], "241861950");
+    }
+
+    #[test]
+    fn should_report_bad_requests() {
+        let config = Config::default();
+
+        let (status, value) = respond(&Method::Post, "/day/1/part/1", "12\nab\n", &config);
+        assert_eq!(status, 422);
+        assert_eq!(
+            value["error"],
+            "day 1, line 2, column 1: invalid digit found in string (at \"ab\")"
+        );
+
+        let (status, value) = respond(&Method::Post, "/day/1/part/1", "1\n", &config);
+        assert_eq!(status, 422);
+        assert_eq!(value["error"], "day 1: no two expenses sum to 2020");
+
+        assert_eq!(respond(&Method::Get, "/day/1", EXPENSES, &config).0, 405);
+        assert_eq!(respond(&Method::Post, "/day/26", EXPENSES, &config).0, 404);
+        assert_eq!(respond(&Method::Post, "/", EXPENSES, &config).0, 404);
+    }
+}
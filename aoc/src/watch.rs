@@ -0,0 +1,201 @@
+//! Solving a day again whenever its input, examples or config change, showing how much faster
+//! or slower each stage got since the last time.
+
+use crate::days::Runner;
+use crate::examples;
+use aoc_common::config::Config;
+use aoc_common::{Part, Report};
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::{
+    collections::HashMap,
+    fs, panic,
+    path::{Path, PathBuf},
+    sync::mpsc,
+    thread,
+    time::Duration,
+};
+
+/// How long to wait after a change for the rest of a save to land before solving again.
+const SETTLE: Duration = Duration::from_millis(100);
+
+/// A day and the files it's solved from.
+pub struct Watch<'a> {
+    pub day: u8,
+    pub run: Runner,
+    /// The puzzle input.
+    pub input: PathBuf,
+    /// The directory holding the `dayN` example directories.
+    pub examples: PathBuf,
+    /// The config file the puzzle input is solved with.
+    pub config: PathBuf,
+    /// Loads the config the puzzle input is solved with.
+    pub load_config: &'a dyn Fn() -> Result<Config, String>,
+}
+
+/// How long each stage took for each file, to compare the next run against.
+type Timings = HashMap<(PathBuf, Option<Part>), Duration>;
+
+impl Watch<'_> {
+    /// Solves the day, then again after every change, until the watcher fails.
+    pub fn run(&self) -> notify::Result<()> {
+        let (tx, rx) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(tx)?;
+        // editors often save by replacing the file, so watch the directories rather than the
+        // files in them
+        let mut dirs = vec![self.examples_dir()];
+        dirs.extend(self.input.parent().map(Path::to_path_buf));
+        dirs.extend(self.config.parent().map(Path::to_path_buf));
+        dirs.sort();
+        dirs.dedup();
+        for dir in dirs.iter().filter(|dir| dir.is_dir()) {
+            watcher.watch(dir, RecursiveMode::NonRecursive)?;
+        }
+
+        let mut timings = Timings::new();
+        let mut run = 1;
+        self.solve(run, &mut timings);
+        while let Ok(event) = rx.recv() {
+            if !self.affected_by(&event?) {
+                continue;
+            }
+            thread::sleep(SETTLE);
+            while rx.try_recv().is_ok() {}
+            run += 1;
+            self.solve(run, &mut timings);
+        }
+        Ok(())
+    }
+
+    fn examples_dir(&self) -> PathBuf {
+        self.examples.join(format!("day{}", self.day))
+    }
+
+    /// Whether `event` changed a file the day is solved from, rather than just reading one.
+    fn affected_by(&self, event: &Event) -> bool {
+        let changed = matches!(
+            event.kind,
+            EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+        );
+        let examples = self.examples_dir();
+        changed
+            && event.paths.iter().any(|path| {
+                *path == self.input || *path == self.config || path.parent() == Some(&examples)
+            })
+    }
+
+    fn solve(&self, run: usize, timings: &mut Timings) {
+        println!("day {}, run {}:", self.day, run);
+
+        match (self.load_config)() {
+            Ok(config) => self.solve_file(&self.input, &config, &[], timings),
+            Err(e) => println!("  {}: {}", self.input.display(), e),
+        }
+
+        for path in examples::inputs(&self.examples, self.day) {
+            let expected = path.with_extension("expected");
+            let expected = match expected.exists() {
+                true => examples::expected_answers(&expected),
+                false => Ok(vec![]),
+            };
+            let config = Config::load(&path.with_extension("toml"));
+            match (config, expected) {
+                (Ok(config), Ok(expected)) => self.solve_file(&path, &config, &expected, timings),
+                (Err(e), _) => println!("  {}: {}", path.display(), e),
+                (_, Err(e)) => println!("  {}: {}", path.display(), e),
+            }
+        }
+        println!();
+    }
+
+    fn solve_file(
+        &self,
+        path: &Path,
+        config: &Config,
+        expected: &[(Part, String)],
+        timings: &mut Timings,
+    ) {
+        let name = path.strip_prefix(&self.examples).unwrap_or(path).display();
+        let input = match fs::read_to_string(path) {
+            Ok(input) => input,
+            Err(e) => return println!("  {}: {}", name, e),
+        };
+        // an input caught halfway through an edit can make a part give up, which shouldn't stop
+        // the watch; the panic hook has already said why
+        let run = self.run;
+        let reports = match panic::catch_unwind(|| run(&Part::ALL, &input, config)) {
+            Ok(Ok(reports)) => reports,
+            Ok(Err(e)) => return println!("  {}: invalid config: {}", name, e),
+            Err(_) => return println!("  {}: panicked", name),
+        };
+
+        let mut time = |stage, took| {
+            let previous = timings.insert((path.to_path_buf(), stage), took);
+            timing(took, previous)
+        };
+        let parse_time = reports.first().map_or(Duration::ZERO, |r| r.parse_time);
+        println!("  {}: parsed in {}", name, time(None, parse_time));
+
+        for Report {
+            part,
+            answer,
+            solve_time,
+            ..
+        } in reports
+        {
+            let answer = match answer {
+                Ok(answer) => answer,
+                Err(e) => {
+                    println!("    part {}: {}", part, e);
+                    continue;
+                }
+            };
+            let wrong = expected
+                .iter()
+                .find(|(p, _)| *p == part)
+                .filter(|(_, e)| *e != answer)
+                .map_or(String::new(), |(_, e)| format!(", expected {}", e));
+            println!(
+                "    part {}: {} in {}{}",
+                part,
+                answer,
+                time(Some(part), solve_time),
+                wrong
+            );
+        }
+    }
+}
+
+/// How long a stage took, and how that compares to the last time if there was one.
+fn timing(took: Duration, previous: Option<Duration>) -> String {
+    let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+    match previous {
+        Some(previous) => {
+            // round before adding the sign, so a change too small to show isn't "-0.000"
+            let change = ((ms(took) - ms(previous)) * 1000.0).round() / 1000.0 + 0.0;
+            format!("{:.3}ms ({:+.3}ms)", ms(took), change)
+        }
+        None => format!("{:.3}ms", ms(took)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::timing;
+    use std::time::Duration;
+
+    #[test]
+    fn should_show_the_change_since_last_time() {
+        let ms = Duration::from_millis;
+
+        assert_eq!(timing(ms(12), None), "12.000ms");
+        assert_eq!(timing(ms(12), Some(ms(10))), "12.000ms (+2.000ms)");
+        assert_eq!(
+            timing(Duration::from_micros(500), Some(ms(2))),
+            "0.500ms (-1.500ms)"
+        );
+        assert_eq!(
+            timing(Duration::from_nanos(1000), Some(Duration::from_nanos(1100))),
+            "0.001ms (+0.000ms)"
+        );
+    }
+}
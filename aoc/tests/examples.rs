@@ -0,0 +1,108 @@
+//! Solves every `examples/dayN/<name>.txt` and checks the answers against its
+//! `<name>.expected` sidecar, see `src/examples.rs` for the layout.
+//!
+//! Days with more than one implementation of a part also have every implementation checked
+//! against the others on the parts their examples list.
+//!
+//! Each example is also solved again as if it had been saved on Windows by a careless editor, with
+//! `\r\n` line endings, trailing spaces and extra blank lines at the end.
+
+#[allow(dead_code)]
+#[path = "../src/days.rs"]
+mod days;
+#[path = "../src/examples.rs"]
+mod examples;
+
+use aoc_common::{config::Config, Part};
+use examples::EXAMPLES;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+fn example_inputs(day: u8) -> Vec<PathBuf> {
+    examples::inputs(Path::new(EXAMPLES), day)
+}
+
+fn expected_answers(path: &Path) -> Vec<(Part, String)> {
+    examples::expected_answers(path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e))
+}
+
+/// Checks every example's answers after passing its input through `mangle`.
+fn check_examples(mangle: impl Fn(&str) -> String) {
+    let mut checked = 0;
+    let mut failures = Vec::new();
+
+    for (day, run) in days::all() {
+        for input_path in example_inputs(day) {
+            let expected = expected_answers(&input_path.with_extension("expected"));
+            let parts = expected.iter().map(|&(part, _)| part).collect::<Vec<_>>();
+            let input = mangle(&fs::read_to_string(&input_path).unwrap());
+            let name = input_path.strip_prefix(EXAMPLES).unwrap().display();
+            let config = Config::load(&input_path.with_extension("toml"))
+                .unwrap_or_else(|e| panic!("{}: {}", name, e));
+            let reports =
+                run(&parts, &input, &config).unwrap_or_else(|e| panic!("{}: {}", name, e));
+
+            for (report, (_, expected)) in reports.into_iter().zip(&expected) {
+                checked += 1;
+                match report.answer {
+                    Ok(actual) if actual == *expected => {}
+                    Ok(actual) => failures.push(format!(
+                        "{} part {}: expected {}, got {}",
+                        name, report.part, expected, actual
+                    )),
+                    Err(e) => failures.push(format!("{} part {}: {}", name, report.part, e)),
+                }
+            }
+        }
+    }
+
+    assert!(checked > 0, "no examples found in {}", EXAMPLES);
+    assert!(
+        failures.is_empty(),
+        "{} of {} example answers were wrong:\n{}",
+        failures.len(),
+        checked,
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn implementations_agree_on_the_examples() {
+    let mut checked = 0;
+    let mut failures = Vec::new();
+
+    for (day, check) in (1..=25).filter_map(|day| Some((day, days::checker(day)?))) {
+        for input_path in example_inputs(day) {
+            let expected = expected_answers(&input_path.with_extension("expected"));
+            let parts = expected.iter().map(|&(part, _)| part).collect::<Vec<_>>();
+            let input = fs::read_to_string(&input_path).unwrap();
+            let name = input_path.strip_prefix(EXAMPLES).unwrap().display();
+            let config = Config::load(&input_path.with_extension("toml"))
+                .unwrap_or_else(|e| panic!("{}: {}", name, e));
+
+            checked += 1;
+            match check(&parts, &input, &config) {
+                Ok(found) => failures.extend(found.iter().map(|d| format!("{}: {}", name, d))),
+                Err(e) => failures.push(format!("{}: {}", name, e)),
+            }
+        }
+    }
+
+    assert!(checked > 0, "no examples found for days with variants");
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn examples_give_the_expected_answers() {
+    check_examples(str::to_owned);
+}
+
+#[test]
+fn examples_tolerate_crlf_and_trailing_whitespace() {
+    check_examples(|input| {
+        let lines = input.lines().map(|l| format!("{}  \r\n", l));
+        lines.collect::<String>() + "\r\n \r\n\n"
+    });
+}
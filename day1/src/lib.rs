@@ -0,0 +1,99 @@
+use aoc_common::arith::Checked;
+use aoc_common::parse::numbers;
+use aoc_common::{ParseError, Solution, SolveError, Unsolvable};
+use itertools::Itertools;
+use serde::Deserialize;
+
+pub struct Day1;
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// What the expenses need to add up to.
+    pub target: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params { target: 2020 }
+    }
+}
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Params = Params;
+    type Parsed = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        numbers(input)
+    }
+
+    fn part1(expenses: &Self::Parsed, params: &Params) -> Result<usize, SolveError> {
+        find_result(expenses, 2, params.target).ok_or_else(|| {
+            Unsolvable::new::<Day1>(format!("no two expenses sum to {}", params.target)).into()
+        })
+    }
+
+    fn part2(expenses: &Self::Parsed, params: &Params) -> Result<usize, SolveError> {
+        find_result(expenses, 3, params.target).ok_or_else(|| {
+            Unsolvable::new::<Day1>(format!("no three expenses sum to {}", params.target)).into()
+        })
+    }
+}
+
+/// The product of the first `amount_of_numbers` expenses that add up to `target`.
+pub fn find_result(expenses: &[usize], amount_of_numbers: usize, target: usize) -> Option<usize> {
+    let sum = Checked::new::<Day1>("the expense sum");
+    let product = Checked::new::<Day1>("the expense product");
+    expenses
+        .iter()
+        .combinations(amount_of_numbers)
+        .find(|c| sum.sum(c.iter().map(|&&x| x)) == target)
+        .map(|c| product.product(c.iter().map(|&&x| x)))
+}
+
+#[cfg(test)]
+#[allow(clippy::unnecessary_cast)]
+mod day1 {
+    use super::{find_result, Day1};
+    use aoc_common::{parses_what_it_prints, Solution};
+    use proptest::{collection::vec, prelude::*};
+
+    /// Expense reports with at least one pair and one triple of entries summing to 2020.
+    fn expenses() -> impl Strategy<Value = Vec<usize>> {
+        (
+            vec(1..2020_usize, 0..20),
+            1..2020_usize,
+            1..1000_usize,
+            1..1000_usize,
+        )
+            .prop_map(|(mut expenses, pair, a, b)| {
+                expenses.extend(&[pair, 2020 - pair, a, b, 2020 - a - b]);
+                expenses
+            })
+            .prop_shuffle()
+    }
+
+    fn print(expenses: &[usize]) -> String {
+        expenses.iter().map(|e| format!("{}\n", e)).collect()
+    }
+
+    parses_what_it_prints!(expenses() => print, Day1::parse);
+
+    #[test]
+    fn part1() {
+        let vec = vec![1721, 979, 366, 299, 675, 1456];
+        let result = find_result(&vec, 2, 2020).unwrap();
+        assert_eq!(514579 as usize, result);
+    }
+
+    #[test]
+    fn part2() {
+        let vec = vec![1721, 979, 366, 299, 675, 1456];
+        let result = find_result(&vec, 3, 2020).unwrap();
+        assert_eq!(241861950, result);
+    }
+}
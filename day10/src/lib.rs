@@ -0,0 +1,143 @@
+use aoc_common::arith::Checked;
+use aoc_common::config::NoParams;
+use aoc_common::parse::numbers;
+use aoc_common::variants::{Variant, Variants};
+use aoc_common::{ParseError, Solution, SolveError};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Params = NoParams;
+    type Parsed = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let mut adapters: Vec<usize> = numbers(input)?;
+        adapters.push(0);
+        adapters.sort_unstable();
+        if let Some(&max) = adapters.last() {
+            adapters.push(max + 3);
+        };
+
+        Ok(adapters)
+    }
+
+    fn part1(adapters: &Self::Parsed, _: &Self::Params) -> Result<usize, SolveError> {
+        Ok(part1(adapters))
+    }
+
+    fn part2(adapters: &Self::Parsed, _: &Self::Params) -> Result<usize, SolveError> {
+        Ok(part2(adapters))
+    }
+}
+
+impl Variants for Day10 {
+    fn part2_variants() -> Vec<Variant<Self, usize>> {
+        vec![Variant::new(
+            "run lengths",
+            |adapters: &Self::Parsed, _: &Self::Params| Ok(part2_run_lengths(adapters)),
+        )]
+    }
+}
+
+fn part1(adapters: &[usize]) -> usize {
+    let diffs = adapters
+        .windows(2)
+        .map(|win| win[1] - win[0])
+        .collect::<Vec<usize>>();
+
+    let count_diffs = |diff| diffs.iter().filter(|&&a| a == diff).count();
+
+    Checked::new::<Day10>("the product of differences").mul(count_diffs(1), count_diffs(3))
+}
+
+/// Counts the ways to reach each adapter from the ones up to 3 jolts below it.
+fn part2(adapters: &[usize]) -> usize {
+    let count = Checked::new::<Day10>("counting arrangements");
+    let mut ways = vec![0_usize; adapters.len()];
+    ways[0] = 1;
+    for (idx, &joltage) in adapters.iter().enumerate().skip(1) {
+        ways[idx] = count.sum(
+            (idx.saturating_sub(3)..idx)
+                .filter(|&from| joltage - adapters[from] <= 3)
+                .map(|from| ways[from]),
+        );
+    }
+    ways.last().copied().unwrap_or(1)
+}
+
+/// Multiplies together the ways through each run of adapters 1 jolt apart, which only works
+/// when every gap is 1 or 3 jolts and no run is more than four gaps long, as in the puzzle inputs.
+fn part2_run_lengths(adapters: &[usize]) -> usize {
+    let get_path_count = |u: &[&[usize]]| match u.len() {
+        // count numbers of paths in the graph based on the amount of matches
+        4 => 7,
+        3 => 4,
+        2 => 2,
+        _ => 1,
+    };
+    // the number of possible paths is equal to the product of the number of paths from each node in the graph
+
+    let runs = adapters
+        .windows(2) //iterate pairs
+        .collect::<Vec<_>>();
+    let paths = runs
+        .split(|&n| n[1] - n[0] == 3) // grab all items up to a diff of 3
+        .map(get_path_count);
+    Checked::new::<Day10>("the arrangement product").product(paths)
+}
+
+#[cfg(test)]
+mod day10 {
+    use super::Day10;
+    use aoc_common::{config::NoParams, solve, variants::disagreements, Part};
+    use proptest::{collection::vec, prelude::*};
+
+    /// The differences between each adapter in a chain, starting from the outlet.
+    fn differences() -> impl Strategy<Value = Vec<usize>> {
+        vec(prop_oneof![Just(1), Just(3)], 1..40)
+    }
+
+    /// Differences like the puzzle's, which never have more than four 1-jolt gaps in a row.
+    fn short_runs() -> impl Strategy<Value = Vec<usize>> {
+        let run = (0..=4_usize).prop_map(|ones| {
+            let mut run = vec![1; ones];
+            run.push(3);
+            run
+        });
+        vec(run, 1..10).prop_map(|runs| runs.concat())
+    }
+
+    /// The adapters in a chain with the given differences, in a random order.
+    fn adapters(differences: &[usize]) -> impl Strategy<Value = String> {
+        let chain = differences
+            .iter()
+            .scan(0, |joltage, difference| {
+                *joltage += difference;
+                Some(format!("{}\n", joltage))
+            })
+            .collect::<Vec<_>>();
+        Just(chain).prop_shuffle().prop_map(|chain| chain.concat())
+    }
+
+    proptest! {
+        #[test]
+        fn multiplies_the_one_and_three_jolt_differences(
+            (differences, input) in differences().prop_flat_map(|d| (Just(d.clone()), adapters(&d)))
+        ) {
+            let count = |jolts| differences.iter().filter(|&&d| d == jolts).count();
+            // the device is always 3 jolts above the last adapter
+            let expected = count(1) * (count(3) + 1);
+            prop_assert_eq!(solve::<Day10>(Part::One, &input), Ok(expected.to_string()));
+        }
+
+        #[test]
+        fn variants_agree(input in short_runs().prop_flat_map(|d| adapters(&d))) {
+            let found = disagreements::<Day10>(&Part::ALL, &input, &NoParams {});
+            prop_assert_eq!(found, Ok(vec![]));
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use aoc_common::automaton::{Automaton, Dense};
+use aoc_common::grid::{Adjacency, Cell, Grid2D, Pos};
+use aoc_common::render::{Frames, Paint, Render};
+use aoc_common::{ParseError, Part, Solution, SolveError};
+use serde::Deserialize;
+use std::io;
+
+pub struct Day11;
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// How many adjacent occupied seats make someone leave in part 1.
+    pub part1_tolerance: usize,
+    /// How many visible occupied seats make someone leave in part 2.
+    pub part2_tolerance: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            part1_tolerance: 4,
+            part2_tolerance: 5,
+        }
+    }
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Params = Params;
+    type Parsed = Grid2D<Seat>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Grid2D::parse(input)
+    }
+
+    fn part1(seats: &Self::Parsed, params: &Params) -> Result<usize, SolveError> {
+        Ok(solve(seats, get_adjacent_seats, params.part1_tolerance))
+    }
+
+    fn part2(seats: &Self::Parsed, params: &Params) -> Result<usize, SolveError> {
+        Ok(solve(seats, get_seats_in_los, params.part2_tolerance))
+    }
+}
+
+impl Render for Day11 {
+    /// A frame for every round until the seating settles.
+    fn render(
+        seats: &Self::Parsed,
+        params: &Params,
+        part: Part,
+        frames: &mut Frames,
+    ) -> io::Result<()> {
+        fn all_rounds<N, R>(frames: &mut Frames, mut automaton: Dense<Seat, N, R>) -> io::Result<()>
+        where
+            Dense<Seat, N, R>: Automaton,
+        {
+            frames.push(automaton.grid())?;
+            while automaton.step() {
+                frames.push(automaton.grid())?;
+            }
+            Ok(())
+        }
+
+        match part {
+            Part::One => all_rounds(
+                frames,
+                seating(seats, get_adjacent_seats, params.part1_tolerance),
+            ),
+            Part::Two => all_rounds(
+                frames,
+                seating(seats, get_seats_in_los, params.part2_tolerance),
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Seat {
+    Floor,
+    Empty,
+    Occupied,
+}
+
+impl Cell for Seat {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Seat::Floor),
+            'L' => Some(Seat::Empty),
+            '#' => Some(Seat::Occupied),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Seat::Floor => '.',
+            Seat::Empty => 'L',
+            Seat::Occupied => '#',
+        }
+    }
+}
+
+impl Paint for Seat {
+    fn rgb(&self) -> [u8; 3] {
+        match self {
+            Seat::Floor => [255, 255, 255],
+            Seat::Empty => [120, 200, 120],
+            Seat::Occupied => [30, 30, 30],
+        }
+    }
+}
+
+type Seats = Grid2D<Seat>;
+
+/// The seating area as an automaton where people leave a seat once `tolerance` of its
+/// `neighbours` are occupied.
+fn seating<N>(
+    seats: &Seats,
+    neighbours: N,
+    tolerance: usize,
+) -> Dense<Seat, N, impl Fn(&Seat, &[Seat]) -> Seat>
+where
+    N: Fn(&Seats, Pos) -> Vec<Pos>,
+{
+    Dense::new(
+        seats.clone(),
+        neighbours,
+        move |&seat, neighbours: &[Seat]| {
+            let occupied = neighbours.iter().filter(|&&s| s == Seat::Occupied).count();
+            match (seat, occupied) {
+                (Seat::Empty, 0) => Seat::Occupied,
+                (Seat::Occupied, a) if a >= tolerance => Seat::Empty,
+                (c, _) => c,
+            }
+        },
+    )
+}
+
+fn solve(seats: &Seats, neighbours: impl Fn(&Seats, Pos) -> Vec<Pos>, tolerance: usize) -> usize {
+    let mut automaton = seating(seats, neighbours, tolerance);
+    automaton.run_until_stable();
+
+    automaton
+        .grid()
+        .cells()
+        .filter(|&&c| c == Seat::Occupied)
+        .count()
+}
+
+fn get_adjacent_seats(input: &Seats, pos: Pos) -> Vec<Pos> {
+    input.neighbours(pos, Adjacency::All).collect()
+}
+
+fn get_seats_in_los(input: &Seats, pos: Pos) -> Vec<Pos> {
+    Adjacency::All
+        .directions()
+        .iter()
+        .filter_map(|&dir| {
+            // the first seat we can see in this direction, looking past the floor
+            input.walk(pos, dir).find(|&p| input[p] != Seat::Floor)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod day11 {
+    use super::Seat;
+    use aoc_common::grid::Grid2D;
+    use proptest::{collection::vec, prelude::*};
+
+    fn layout() -> impl Strategy<Value = String> {
+        (1..10_usize, 1..10_usize).prop_flat_map(|(width, height)| {
+            vec(vec(prop_oneof![Just('.'), Just('L')], width), height).prop_map(|rows| {
+                rows.iter()
+                    .map(|row| row.iter().collect::<String>() + "\n")
+                    .collect()
+            })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn prints_what_it_parses(input in layout()) {
+            let seats = Grid2D::<Seat>::parse(&input).unwrap();
+            prop_assert_eq!(seats.to_string(), input.trim_end());
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use aoc_common::config::NoParams;
+use aoc_common::geometry::{Heading, Vec2};
+use aoc_common::parse::value;
+use aoc_common::{ParseError, Solution, SolveError};
+
+pub struct Day12;
+
+/// A navigation action and its value, e.g. `('F', 10)`.
+pub type Instruction = (char, i64);
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Params = NoParams;
+    type Parsed = Vec<Instruction>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.lines().map(parse_instruction).collect()
+    }
+
+    fn part1(instructions: &Self::Parsed, _: &Self::Params) -> Result<i64, SolveError> {
+        Ok(part1(instructions))
+    }
+
+    fn part2(instructions: &Self::Parsed, _: &Self::Params) -> Result<i64, SolveError> {
+        Ok(part2(instructions))
+    }
+}
+
+fn parse_instruction(line: &str) -> Result<Instruction, ParseError> {
+    let action = match line.chars().next() {
+        Some(c) if "NSEWLRF".contains(c) => c,
+        _ => return Err(ParseError::new(line, "expected an action from `NSEWLRF`")),
+    };
+    let value_text = &line[1..];
+    let amount = value(value_text)?;
+    if (action == 'L' || action == 'R') && amount % 90 != 0 {
+        return Err(ParseError::new(
+            value_text,
+            "turns must be a multiple of 90 degrees",
+        ));
+    }
+
+    Ok((action, amount))
+}
+
+fn part1(instructions: &[Instruction]) -> i64 {
+    let mut pos = Vec2::ZERO;
+    let mut dir = Heading::East.unit();
+    for &(action, amount) in instructions {
+        match action {
+            'L' | 'R' => dir = turn(dir, (action, amount)),
+            'F' => pos += dir * amount,
+            _ => pos += heading(action) * amount,
+        };
+    }
+
+    pos.manhattan()
+}
+
+fn part2(instructions: &[Instruction]) -> i64 {
+    let mut pos = Vec2::ZERO;
+    let mut wp = Vec2::new(10, 1);
+    for &(action, amount) in instructions {
+        match action {
+            'L' | 'R' => wp = turn(wp, (action, amount)),
+            'F' => pos += wp * amount,
+            _ => wp += heading(action) * amount,
+        };
+    }
+    pos.manhattan()
+}
+
+/// One step in the direction of a `NSEW` action.
+fn heading(action: char) -> Vec2 {
+    Heading::from_char(action)
+        .expect("actions are checked when parsing")
+        .unit()
+}
+
+fn turn(facing: Vec2, (action, degrees): Instruction) -> Vec2 {
+    match action {
+        'L' => facing.rotate_left(degrees / 90),
+        _ => facing.rotate_right(degrees / 90),
+    }
+}
+
+#[cfg(test)]
+mod day12 {
+    use super::{parse_instruction, turn, Instruction};
+    use aoc_common::geometry::Vec2;
+    use aoc_common::parses_what_it_prints;
+    use proptest::prelude::*;
+
+    fn instruction() -> impl Strategy<Value = Instruction> {
+        let turn = (prop_oneof![Just('L'), Just('R')], 1..4_i64).prop_map(|(a, q)| (a, q * 90));
+        let movement = (
+            prop::sample::select(vec!['N', 'S', 'E', 'W', 'F']),
+            0..100_i64,
+        );
+        prop_oneof![turn, movement]
+    }
+
+    fn print(instructions: &[Instruction]) -> String {
+        instructions
+            .iter()
+            .map(|(action, amount)| format!("{}{}\n", action, amount))
+            .collect()
+    }
+
+    parses_what_it_prints!(instruction() => |i: &Instruction| print(&[*i]), |line: &str| {
+        parse_instruction(line.trim_end())
+    });
+
+    proptest! {
+        #[test]
+        fn turning_left_undoes_turning_right(
+            (x, y) in (-1000..1000_i64, -1000..1000_i64),
+            degrees in prop_oneof![Just(90), Just(180), Just(270)],
+        ) {
+            let waypoint = Vec2::new(x, y);
+            let there = turn(waypoint, ('R', degrees));
+            prop_assert_eq!(turn(there, ('L', degrees)), waypoint);
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use aoc_common::arith::Checked;
+use aoc_common::config::NoParams;
+use aoc_common::number_theory::crt;
+use aoc_common::parse::value;
+use aoc_common::{ParseError, Solution, SolveError, Unsolvable};
+
+pub struct Day13;
+
+/// The earliest departure time and each bus id paired with its offset.
+pub type Notes = (usize, Vec<(usize, usize)>);
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Params = NoParams;
+    type Parsed = Notes;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(notes: &Self::Parsed, _: &Self::Params) -> Result<usize, SolveError> {
+        part1(notes).ok_or_else(|| Unsolvable::new::<Day13>("every bus is out of service").into())
+    }
+
+    fn part2(notes: &Self::Parsed, _: &Self::Params) -> Result<usize, SolveError> {
+        Ok(part2(notes))
+    }
+}
+
+fn parse_input(input: &str) -> Result<Notes, ParseError> {
+    let mut lines = input.lines();
+    let mut next_line = |what| {
+        lines
+            .next()
+            .ok_or_else(|| ParseError::new(&input[input.len()..], format!("missing {}", what)))
+    };
+    let timestamp = value(next_line("the earliest timestamp")?)?;
+    let busses = next_line("the bus ids")?
+        .split(',')
+        .enumerate()
+        .filter(|&(_, b)| b != "x")
+        .map(|(i, b)| match value(b)? {
+            0 => Err(ParseError::new(b, "bus ids must be positive")),
+            bus => Ok((i, bus)),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((timestamp, busses))
+}
+
+fn get_lowest_multiple_after_ts(timestamp: &usize, bus: &usize) -> usize {
+    Checked::new::<Day13>("the next departure").mul(*bus, timestamp.div_ceil(*bus))
+}
+
+fn part1(input: &Notes) -> Option<usize> {
+    let (ts, busses) = input;
+
+    let earliest_bus = busses
+        .iter()
+        .map(|&(idx, bus)| (idx, bus, get_lowest_multiple_after_ts(ts, &bus)))
+        .min_by_key(|&b| b.2)?;
+
+    // bus number * (lowest_mult_after_ts - ts)
+    Some(earliest_bus.1 * (earliest_bus.2 - ts))
+}
+
+fn part2(input: &Notes) -> usize {
+    let (_, busses) = input;
+
+    // super helpful: https://www.dave4math.com/mathematics/chinese-remainder-theorem/
+    let cast = Checked::new::<Day13>("converting for the remainder theorem");
+    let congruences = busses.iter().map(|&(bus_idx, bus)| {
+        let offset: i64 = cast.convert(bus_idx);
+        (-offset, cast.convert(bus))
+    });
+    let (earliest, _) = crt(congruences)
+        .expect("the busses never depart at their offsets, or not within a u64 of time");
+    cast.convert(earliest)
+}
+
+#[cfg(test)]
+mod day13 {
+    use super::{parse_input, Day13, Notes};
+    use aoc_common::{parses_what_it_prints, solve, Part};
+    use proptest::{collection::vec, prelude::*, sample::subsequence};
+
+    const PRIMES: [usize; 12] = [7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47];
+
+    /// Notes whose bus ids are distinct primes, so there's always a part 2 answer.
+    fn notes() -> impl Strategy<Value = Notes> {
+        (1..10_000_usize, subsequence(PRIMES.to_vec(), 1..6))
+            .prop_flat_map(|(timestamp, busses)| {
+                let gaps = vec(0..3_usize, busses.len());
+                (Just(timestamp), Just(busses).prop_shuffle(), gaps)
+            })
+            .prop_map(|(timestamp, busses, gaps)| {
+                let offsets = gaps.iter().scan(0, |offset, gap| {
+                    let this = *offset + gap;
+                    *offset = this + 1;
+                    Some(this)
+                });
+                (timestamp, offsets.zip(busses).collect())
+            })
+    }
+
+    fn print((timestamp, busses): &Notes) -> String {
+        let slots = busses.last().map_or(0, |&(offset, _)| offset + 1);
+        let slots = (0..slots)
+            .map(
+                |slot| match busses.iter().find(|&&(offset, _)| offset == slot) {
+                    Some((_, bus)) => bus.to_string(),
+                    None => String::from("x"),
+                },
+            )
+            .collect::<Vec<_>>();
+        format!("{}\n{}\n", timestamp, slots.join(","))
+    }
+
+    parses_what_it_prints!(notes() => print, parse_input);
+
+    proptest! {
+        #[test]
+        fn departs_each_bus_at_its_offset(notes in notes()) {
+            let earliest: usize = solve::<Day13>(Part::Two, &print(&notes)).unwrap().parse().unwrap();
+            let period = notes.1.iter().map(|&(_, bus)| bus).product::<usize>();
+
+            prop_assert!(earliest < period);
+            for &(offset, bus) in notes.1.iter() {
+                prop_assert_eq!((earliest + offset) % bus, 0);
+            }
+        }
+    }
+
+    #[test]
+    fn should_report_when_no_bus_runs() {
+        let answer = solve::<Day13>(Part::One, "939\nx,x\n").map_err(|e| e.to_string());
+        assert_eq!(
+            answer,
+            Err(String::from("day 13: every bus is out of service"))
+        );
+    }
+}
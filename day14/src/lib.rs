@@ -0,0 +1,313 @@
+use aoc_common::config::NoParams;
+use aoc_common::parse::value;
+use aoc_common::variants::{Variant, Variants};
+use aoc_common::{ParseError, Solution, SolveError};
+use regex::Regex;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Params = NoParams;
+    type Parsed = Vec<Instruction>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.lines().map(str::parse).collect()
+    }
+
+    fn part1(instructions: &Self::Parsed, _: &Self::Params) -> Result<u64, SolveError> {
+        Ok(part1(instructions))
+    }
+
+    fn part2(instructions: &Self::Parsed, _: &Self::Params) -> Result<u64, SolveError> {
+        Ok(part2(instructions))
+    }
+}
+
+impl Variants for Day14 {
+    fn part2_variants() -> Vec<Variant<Self, u64>> {
+        vec![Variant::new(
+            "every address",
+            |instructions: &Self::Parsed, _: &Self::Params| Ok(part2_every_address(instructions)),
+        )]
+    }
+}
+
+fn part1(instructions: &[Instruction]) -> u64 {
+    let mut mem = HashMap::new();
+    let mut current_mask = &vec![];
+    for inst in instructions {
+        match inst {
+            Instruction::Mask { m } => current_mask = m,
+            Instruction::Mem { loc, mut value } => {
+                for (idx, bit) in current_mask.iter().rev().enumerate() {
+                    value = match bit {
+                        Some(true) => value | (1 << idx),
+                        Some(false) => value & (!(1 << idx)),
+                        None => value,
+                    }
+                }
+                mem.insert(loc, value);
+            }
+        }
+    }
+    mem.values().sum()
+}
+
+/// Works backwards through the writes, adding up each value for the addresses it writes that no
+/// later write overwrites, so floating bits never have to be expanded.
+fn part2(instructions: &[Instruction]) -> u64 {
+    let mut writes = Vec::new();
+    let mut current_mask = &vec![];
+    for inst in instructions {
+        match inst {
+            Instruction::Mask { m } => current_mask = m,
+            Instruction::Mem { loc, value } => {
+                writes.push((Addresses::masked(*loc, current_mask), *value))
+            }
+        }
+    }
+
+    let mut later: Vec<Addresses> = Vec::new();
+    let mut total = 0;
+    for (addresses, value) in writes.into_iter().rev() {
+        let mut kept = vec![addresses];
+        for &overwritten in later.iter() {
+            kept = kept
+                .into_iter()
+                .flat_map(|a| a.without(overwritten))
+                .collect();
+        }
+        total += value * kept.iter().map(Addresses::len).sum::<u64>();
+        later.push(addresses);
+    }
+    total
+}
+
+/// Writes every value to every address it floats over.
+fn part2_every_address(instructions: &[Instruction]) -> u64 {
+    let mut mem = HashMap::new();
+    let mut current_mask = &vec![];
+
+    for inst in instructions {
+        match inst {
+            Instruction::Mask { m } => current_mask = m,
+            Instruction::Mem { loc, value } => {
+                let mut poss_addresses = vec![*loc];
+                for (idx, bit) in current_mask.iter().rev().enumerate() {
+                    let bits = 1 << idx;
+                    match bit {
+                        Some(true) => {
+                            for addr in &mut poss_addresses {
+                                *addr |= bits;
+                            }
+                        }
+                        Some(false) => {}
+                        None => {
+                            // this takes into account every possible variation of the existing variations
+                            // out there, (its a lot of combinations!!)
+                            for addr in poss_addresses.clone() {
+                                poss_addresses.push(addr ^ bits);
+                            }
+                        }
+                    }
+                }
+                // end of bit iteration, time to set the memory at all possible addresses
+                for addr in poss_addresses {
+                    mem.insert(addr, *value);
+                }
+            }
+        }
+    }
+
+    mem.values().sum()
+}
+
+/// The addresses matching a pattern, where each `floating` bit can be either value and the rest
+/// are taken from `fixed`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Addresses {
+    fixed: u64,
+    floating: u64,
+}
+
+impl Addresses {
+    /// The addresses a write to `loc` goes to under `mask`.
+    fn masked(loc: u64, mask: &[Option<bool>]) -> Addresses {
+        let (mut fixed, mut floating) = (loc, 0);
+        for (idx, bit) in mask.iter().rev().enumerate() {
+            match bit {
+                Some(true) => fixed |= 1 << idx,
+                Some(false) => {}
+                None => floating |= 1 << idx,
+            }
+        }
+        Addresses {
+            fixed: fixed & !floating,
+            floating,
+        }
+    }
+
+    fn len(&self) -> u64 {
+        1 << self.floating.count_ones()
+    }
+
+    fn overlaps(self, other: Addresses) -> bool {
+        (self.fixed ^ other.fixed) & !self.floating & !other.floating == 0
+    }
+
+    /// The addresses that aren't also in `other`, as patterns that don't overlap each other.
+    fn without(self, other: Addresses) -> Vec<Addresses> {
+        if !self.overlaps(other) {
+            return vec![self];
+        }
+
+        // fix each bit that floats here but not in `other` in turn: the half that differs from
+        // `other` is clear of it, and the half that matches carries on to the next bit, until
+        // what's left is entirely inside `other`
+        let mut pieces = Vec::new();
+        let mut rest = self;
+        let mut split = self.floating & !other.floating;
+        while split != 0 {
+            let bit = split & split.wrapping_neg();
+            split &= !bit;
+            rest.floating &= !bit;
+            pieces.push(Addresses {
+                fixed: rest.fixed | (!other.fixed & bit),
+                floating: rest.floating,
+            });
+            rest.fixed |= other.fixed & bit;
+        }
+        pieces
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Instruction {
+    Mem { loc: u64, value: u64 },
+    Mask { m: Vec<Option<bool>> },
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix("mask = ") {
+            Some(mask) => parse_mask(mask),
+            None => parse_mem(s),
+        }
+    }
+}
+
+fn parse_mem(mem: &str) -> Result<Instruction, ParseError> {
+    let re = Regex::new(r"^mem\[(\d*)\] = (\d*)$").unwrap();
+    let captures = re
+        .captures(mem)
+        .ok_or_else(|| ParseError::new(mem, "expected `mask = ...` or `mem[address] = value`"))?;
+    Ok(Instruction::Mem {
+        loc: value(captures.get(1).unwrap().as_str())?,
+        value: value(captures.get(2).unwrap().as_str())?,
+    })
+}
+
+fn parse_mask(mask: &str) -> Result<Instruction, ParseError> {
+    let m = mask
+        .char_indices()
+        .map(|(idx, c)| match c {
+            '0' => Ok(Some(false)),
+            '1' => Ok(Some(true)),
+            'X' => Ok(None),
+            _ => Err(ParseError::new(
+                &mask[idx..idx + c.len_utf8()],
+                "expected `0`, `1` or `X`",
+            )),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if m.len() != 36 {
+        return Err(ParseError::new(mask, "expected a 36 bit mask"));
+    }
+
+    Ok(Instruction::Mask { m })
+}
+
+#[cfg(test)]
+mod day14 {
+    use super::{Day14, Instruction};
+    use aoc_common::{
+        config::NoParams, parses_what_it_prints, variants::disagreements, Part, Solution,
+    };
+    use proptest::{collection::vec, prelude::*, sample::Index};
+
+    /// A mask with at most six floating bits, so part 2 doesn't write to too many addresses.
+    fn mask() -> impl Strategy<Value = Instruction> {
+        (vec(any::<bool>(), 36), vec(any::<Index>(), 0..=6)).prop_map(|(bits, floating)| {
+            let mut m = bits.into_iter().map(Some).collect::<Vec<_>>();
+            for idx in floating {
+                m[idx.index(36)] = None;
+            }
+            Instruction::Mask { m }
+        })
+    }
+
+    fn program() -> impl Strategy<Value = Vec<Instruction>> {
+        let mem = (0..1_u64 << 16, 0..1_u64 << 36)
+            .prop_map(|(loc, value)| Instruction::Mem { loc, value });
+        vec((mask(), vec(mem, 1..5)), 1..6).prop_map(|blocks| {
+            blocks
+                .into_iter()
+                .flat_map(|(mask, writes)| std::iter::once(mask).chain(writes))
+                .collect()
+        })
+    }
+
+    /// A program where every write lands in the same 64 addresses, so later writes overwrite parts
+    /// of earlier ones.
+    fn crowded_program() -> impl Strategy<Value = Vec<Instruction>> {
+        let bit = prop_oneof![Just(Some(false)), Just(Some(true)), Just(None)];
+        let mask = vec(bit, 6).prop_map(|low| {
+            let mut m = vec![Some(false); 30];
+            m.extend(low);
+            Instruction::Mask { m }
+        });
+        let mem = (0..64_u64, 0..1000_u64).prop_map(|(loc, value)| Instruction::Mem { loc, value });
+        vec((mask, vec(mem, 1..5)), 1..8).prop_map(|blocks| {
+            blocks
+                .into_iter()
+                .flat_map(|(mask, writes)| std::iter::once(mask).chain(writes))
+                .collect()
+        })
+    }
+
+    fn print(program: &[Instruction]) -> String {
+        let bit = |b: &Option<bool>| match b {
+            Some(true) => '1',
+            Some(false) => '0',
+            None => 'X',
+        };
+        program
+            .iter()
+            .map(|i| match i {
+                Instruction::Mask { m } => {
+                    format!("mask = {}\n", m.iter().map(bit).collect::<String>())
+                }
+                Instruction::Mem { loc, value } => format!("mem[{}] = {}\n", loc, value),
+            })
+            .collect()
+    }
+
+    parses_what_it_prints!(program() => |p: &Vec<_>| print(p), Day14::parse);
+
+    proptest! {
+
+        #[test]
+        fn variants_agree(program in prop_oneof![program(), crowded_program()]) {
+            let found = disagreements::<Day14>(&Part::ALL, &print(&program), &NoParams {});
+            prop_assert_eq!(found, Ok(vec![]));
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use aoc_common::parse::comma_separated;
+use aoc_common::progress::Task;
+use aoc_common::variants::{Variant, Variants};
+use aoc_common::{ParseError, Solution, SolveError};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+pub struct Day15;
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// The turn whose number is the answer to part 1.
+    pub part1_turns: usize,
+    /// The turn whose number is the answer to part 2.
+    pub part2_turns: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            part1_turns: 2020,
+            part2_turns: 30000000,
+        }
+    }
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Params = Params;
+    type Parsed = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        comma_separated(input)
+    }
+
+    fn part1(starting: &Self::Parsed, params: &Params) -> Result<usize, SolveError> {
+        Ok(solve(starting, params.part1_turns))
+    }
+
+    fn part2(starting: &Self::Parsed, params: &Params) -> Result<usize, SolveError> {
+        Ok(solve(starting, params.part2_turns))
+    }
+
+    fn check_params(params: &Params) -> Result<(), String> {
+        if params.part1_turns == 0 || params.part2_turns == 0 {
+            return Err(String::from("the game needs to last at least 1 turn"));
+        }
+        // `solve` keeps turns as u32 to halve the size of its table
+        let most = u32::MAX as usize;
+        if params.part1_turns > most || params.part2_turns > most {
+            return Err(format!("the game can last at most {} turns", most));
+        }
+        Ok(())
+    }
+}
+
+impl Variants for Day15 {
+    fn part1_variants() -> Vec<Variant<Self, usize>> {
+        vec![Variant::new(
+            "hash map",
+            |starting: &Self::Parsed, params: &Params| {
+                Ok(solve_with_map(starting, params.part1_turns))
+            },
+        )]
+    }
+
+    fn part2_variants() -> Vec<Variant<Self, usize>> {
+        vec![Variant::new(
+            "hash map",
+            |starting: &Self::Parsed, params: &Params| {
+                Ok(solve_with_map(starting, params.part2_turns))
+            },
+        )]
+    }
+}
+
+/// The number spoken on turn `rounds` of the memory game, starting from `input`.
+///
+/// Every number spoken after the starting ones is an age, so smaller than the number of turns,
+/// and the turn each was last spoken on fits in an array indexed by the number. Starting numbers
+/// too large for it are kept aside, as they're only looked up for the first turn after them.
+pub fn solve(input: &[usize], rounds: usize) -> usize {
+    if rounds <= input.len() {
+        return input[rounds - 1];
+    }
+
+    // 0 means never spoken, otherwise it's the turn, counting from 1. The last starting number
+    // is only recorded once the turn after it has looked up when it was spoken before.
+    let mut spoken = vec![0_u32; rounds];
+    let mut larger = HashMap::new();
+    let (&last, before) = input.split_last().unwrap();
+    for (turn, &num) in (1..).zip(before) {
+        match spoken.get_mut(num) {
+            Some(last_turn) => *last_turn = turn,
+            None => {
+                larger.insert(num, turn);
+            }
+        }
+    }
+
+    let mut task = Task::new::<Day15>("speaking numbers", (rounds - input.len()) as u64);
+    let mut last_spoken = last;
+    for turn in input.len()..rounds {
+        if !task.tick() {
+            break;
+        }
+        let last_turn = match spoken.get_mut(last_spoken) {
+            Some(last_turn) => std::mem::replace(last_turn, turn as u32),
+            None => larger.get(&last_spoken).copied().unwrap_or(0),
+        };
+        last_spoken = match last_turn {
+            0 => 0,
+            last_turn => turn - last_turn as usize,
+        };
+    }
+    last_spoken
+}
+
+/// [`solve`] keeping track of when numbers were spoken in a hash map.
+pub fn solve_with_map(input: &[usize], rounds: usize) -> usize {
+    if rounds <= input.len() {
+        return input[rounds - 1];
+    }
+
+    let mut spoken: HashMap<usize, usize> = HashMap::new();
+    let (&last, before) = input.split_last().unwrap();
+
+    for (idx, num) in before.iter().enumerate() {
+        spoken.insert(*num, idx);
+    }
+
+    let mut last_spoken = last;
+
+    for idx in input.len()..rounds {
+        let current = match spoken.get(&last_spoken) {
+            None => 0,
+            Some(last_spoken_idx) => idx - last_spoken_idx - 1,
+        };
+
+        spoken.insert(last_spoken, idx - 1);
+        last_spoken = current;
+    }
+    last_spoken
+}
+
+#[cfg(test)]
+mod day15 {
+    use super::{solve, solve_with_map, Day15, Params};
+    use aoc_common::{
+        config::Config, parses_what_it_prints, variants::disagreements, Part, Solution,
+    };
+    use proptest::{collection::vec, prelude::*, sample::subsequence};
+
+    fn starting() -> impl Strategy<Value = Vec<usize>> {
+        subsequence((0..20).collect::<Vec<usize>>(), 1..8).prop_shuffle()
+    }
+
+    /// Starting numbers that may repeat, which the puzzle's never do, and may be larger than
+    /// the number of turns.
+    fn repeating() -> impl Strategy<Value = Vec<usize>> {
+        vec(prop_oneof![0..5_usize, Just(99_999_999_999)], 1..8)
+    }
+
+    /// Every number spoken up to turn `turns`, following the rules as they're written.
+    fn spoken(starting: &[usize], turns: usize) -> Vec<usize> {
+        let mut spoken = starting.to_vec();
+        while spoken.len() < turns {
+            let (last, before) = spoken.split_last().unwrap();
+            let age = before
+                .iter()
+                .rev()
+                .position(|n| n == last)
+                .map_or(0, |age| age + 1);
+            spoken.push(age);
+        }
+        spoken
+    }
+
+    fn print(starting: &[usize]) -> String {
+        let numbers = starting.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+        numbers.join(",") + "\n"
+    }
+
+    parses_what_it_prints!(starting() => |s: &Vec<_>| print(s), Day15::parse);
+
+    proptest! {
+        #[test]
+        fn speaks_the_starting_numbers_first(starting in starting()) {
+            for (turn, &number) in (1..).zip(starting.iter()) {
+                prop_assert_eq!(solve(&starting, turn), number);
+            }
+        }
+
+        #[test]
+        fn follows_the_rules_with_repeated_starting_numbers(
+            starting in repeating(),
+            turns in 1..100_usize,
+        ) {
+            let expected = spoken(&starting, turns)[turns - 1];
+            prop_assert_eq!(solve(&starting, turns), expected);
+            prop_assert_eq!(solve_with_map(&starting, turns), expected);
+        }
+
+        #[test]
+        fn variants_agree(starting in starting()) {
+            let params = Params {
+                part2_turns: 20_000,
+                ..Params::default()
+            };
+            let found = disagreements::<Day15>(&Part::ALL, &print(&starting), &params);
+            prop_assert_eq!(found, Ok(vec![]));
+        }
+    }
+
+    #[test]
+    fn should_reject_a_game_without_turns() {
+        let config = Config::parse("[day15]\npart1_turns = 0\n").unwrap();
+
+        assert_eq!(
+            config.params::<Day15>().unwrap_err().to_string(),
+            "[day15]: the game needs to last at least 1 turn"
+        );
+    }
+
+    #[test]
+    fn should_reject_a_game_too_long_to_count_the_turns_of() {
+        let config = Config::parse("[day15]\npart2_turns = 4294967296\n").unwrap();
+        assert_eq!(
+            config.params::<Day15>().unwrap_err().to_string(),
+            "[day15]: the game can last at most 4294967295 turns"
+        );
+    }
+}
@@ -0,0 +1,315 @@
+use aoc_common::arith::Checked;
+use aoc_common::config::NoParams;
+use aoc_common::explain::Explain;
+use aoc_common::graph::bipartite_matching;
+use aoc_common::parse::{comma_separated, sections, value};
+use aoc_common::{ParseError, Solution, SolveError, Unsolvable};
+use std::{ops::RangeInclusive, str::FromStr};
+
+pub struct Day16;
+
+/// The field rules, your ticket and the nearby tickets.
+pub type Notes = (Vec<Field>, Ticket, Vec<Ticket>);
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Params = NoParams;
+    type Parsed = Notes;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(notes: &Self::Parsed, _: &Self::Params) -> Result<usize, SolveError> {
+        Ok(pt1(notes))
+    }
+
+    fn part2(notes: &Self::Parsed, _: &Self::Params) -> Result<usize, SolveError> {
+        Ok(pt2(notes)?)
+    }
+}
+
+pub type Ticket = Vec<usize>;
+
+fn pt1(notes: &Notes) -> usize {
+    let (fields, _, tickets) = notes;
+
+    tickets
+        .iter()
+        .flat_map(|t| get_invalid_fields_on_ticket(t, fields))
+        .sum::<usize>()
+}
+
+fn pt2(notes: &Notes) -> Result<usize, Unsolvable> {
+    let (fields, my_ticket, other_tickets) = notes;
+    let valid_tickets = other_tickets
+        .iter()
+        .filter(|t| is_valid_ticket(t, fields))
+        .collect::<Vec<_>>();
+
+    // a column can hold any field that fits its value on every valid ticket
+    let candidates = (0..my_ticket.len())
+        .map(|idx| {
+            let fits = fields
+                .iter()
+                .filter(|f| valid_tickets.iter().all(|t| f.contains(t[idx])))
+                .collect::<Vec<_>>();
+            (idx, fits)
+        })
+        .collect::<Vec<_>>();
+    let matching = bipartite_matching(candidates.iter().cloned());
+    if !matching.is_unique() {
+        let columns = matching.unmatched.iter().chain(matching.ambiguous.iter());
+        let reason = format!(
+            "can't tell which field is in columns {:?}",
+            columns.collect::<Vec<_>>()
+        );
+        return Err(Unsolvable::new::<Day16>(reason));
+    }
+
+    // in the order they'd be found by elimination, the columns with the fewest fits first
+    let explain = Explain::new::<Day16>("resolving fields");
+    if explain.is_on() {
+        let mut resolved = matching.pairs.iter().collect::<Vec<_>>();
+        resolved.sort_by_key(|&&(idx, _)| candidates[idx].1.len());
+        for (idx, field) in resolved {
+            explain.note(|| {
+                let fits = candidates[*idx].1.len();
+                format!("column {} is {}, out of {} that fit", idx, field.name, fits)
+            });
+        }
+    }
+
+    let departures = matching
+        .pairs
+        .iter()
+        .filter(|(_, f)| f.name.starts_with("departure"))
+        .map(|&(idx, _)| my_ticket[idx]);
+    Ok(Checked::new::<Day16>("the departure product").product(departures))
+}
+
+fn parse_input(input: &str) -> Result<Notes, ParseError> {
+    let mut sections = sections(input);
+    let mut next_section = |header: &str| {
+        let section = sections.next().unwrap_or(&input[input.len()..]);
+        match section.strip_prefix(header) {
+            Some(rest) => Ok(rest.trim_start_matches('\n')),
+            None => Err(ParseError::new(section, format!("expected {:?}", header))),
+        }
+    };
+
+    let fields = next_section("")?
+        .lines()
+        .map(str::parse)
+        .collect::<Result<Vec<Field>, _>>()?;
+
+    // every ticket needs a value for each field, or part 2 can't place them
+    let ticket = |line: &str| -> Result<Ticket, ParseError> {
+        let ticket: Ticket = comma_separated(line)?;
+        if ticket.len() != fields.len() {
+            let reason = format!("expected {} values, one per field", fields.len());
+            return Err(ParseError::new(line, reason));
+        }
+        Ok(ticket)
+    };
+
+    let my_ticket = ticket(next_section("your ticket:")?)?;
+
+    let other_tickets = next_section("nearby tickets:")?
+        .lines()
+        .map(ticket)
+        .collect::<Result<Vec<Ticket>, _>>()?;
+
+    Ok((fields, my_ticket, other_tickets))
+}
+
+/// Whether every value on the ticket fits at least one field.
+pub fn is_valid_ticket(ticket: &[usize], fields: &[Field]) -> bool {
+    get_invalid_fields_on_ticket(ticket, fields).is_empty()
+}
+
+/// The values on the ticket that don't fit any field.
+pub fn get_invalid_fields_on_ticket<'a>(
+    ticket: &'a [usize],
+    fields: &'a [Field],
+) -> Vec<&'a usize> {
+    ticket
+        .iter()
+        .filter(|&i| fields.iter().all(|f| !f.contains(*i)))
+        .collect()
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Field {
+    name: String,
+    ranges: Vec<RangeInclusive<usize>>,
+}
+
+impl FromStr for Field {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, ranges) = s
+            .split_once(':')
+            .ok_or_else(|| ParseError::new(s, "expected `name: a-b or c-d`"))?;
+        let ranges = ranges
+            .trim()
+            .split(" or ")
+            .map(|ra| match ra.split_once('-') {
+                Some((from, to)) => Ok(value(from)?..=value(to)?),
+                None => Err(ParseError::new(ra, "expected a range like `1-3`")),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Field {
+            name: String::from(name.trim()),
+            ranges,
+        })
+    }
+}
+
+impl Field {
+    /// Whether the value fits one of the field's ranges.
+    pub fn contains(&self, i: usize) -> bool {
+        self.ranges.iter().any(|r| r.contains(&i))
+    }
+}
+
+#[cfg(test)]
+mod day16 {
+    use super::Day16;
+    use aoc_common::{parse_input, solve, Part};
+    use proptest::{collection::vec, prelude::*};
+
+    /// Notes with `fields` fields, where field `i` only accepts values whose tens digit is
+    /// `i`, so every column's field is known after a single ticket. Each field is named in
+    /// `departure`, and the columns on the tickets are shuffled by `order`. The first nearby
+    /// ticket is always valid, since part 2 can't place the fields without one.
+    #[derive(Debug, Clone)]
+    struct Generated {
+        departure: Vec<bool>,
+        order: Vec<usize>,
+        mine: Vec<usize>,
+        nearby: Vec<Vec<usize>>,
+        /// Values on nearby tickets that don't fit any field.
+        invalid: Vec<usize>,
+    }
+
+    fn notes() -> impl Strategy<Value = Generated> {
+        (2..6_usize)
+            .prop_flat_map(|fields| {
+                let ticket = move || vec(0..10_usize, fields);
+                (
+                    vec(any::<bool>(), fields),
+                    Just((0..fields).collect::<Vec<_>>()).prop_shuffle(),
+                    ticket(),
+                    ticket(),
+                    vec((ticket(), prop::option::of(500..600_usize)), 0..5),
+                )
+            })
+            .prop_map(|(departure, order, mine, valid, nearby)| {
+                let place = |units: Vec<usize>| {
+                    order
+                        .iter()
+                        .zip(units)
+                        .map(|(&f, u)| f * 10 + u)
+                        .collect::<Vec<_>>()
+                };
+                let mut invalid = vec![];
+                let nearby = std::iter::once((valid, None))
+                    .chain(nearby)
+                    .map(|(units, bad)| {
+                        let mut ticket = place(units);
+                        if let Some(bad) = bad {
+                            let column = bad % ticket.len();
+                            ticket[column] = bad;
+                            invalid.push(bad);
+                        }
+                        ticket
+                    })
+                    .collect();
+                Generated {
+                    mine: place(mine),
+                    departure,
+                    order,
+                    nearby,
+                    invalid,
+                }
+            })
+    }
+
+    fn print(notes: &Generated) -> String {
+        let ticket = |t: &Vec<usize>| {
+            let values = t.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+            values.join(",") + "\n"
+        };
+        let fields = notes.departure.iter().enumerate().map(|(f, &departure)| {
+            let name = if departure { "departure" } else { "arrival" };
+            format!(
+                "{} {}: {}-{} or {}-{}\n",
+                name,
+                f,
+                f * 10,
+                f * 10 + 4,
+                f * 10 + 5,
+                f * 10 + 9
+            )
+        });
+
+        format!(
+            "{}\nyour ticket:\n{}\nnearby tickets:\n{}",
+            fields.collect::<String>(),
+            ticket(&notes.mine),
+            notes.nearby.iter().map(ticket).collect::<String>()
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn sums_the_invalid_values(notes in notes()) {
+            let expected = notes.invalid.iter().sum::<usize>();
+            prop_assert_eq!(solve::<Day16>(Part::One, &print(&notes)), Ok(expected.to_string()));
+        }
+
+        #[test]
+        fn multiplies_the_departure_values(notes in notes()) {
+            let expected = notes
+                .order
+                .iter()
+                .zip(notes.mine.iter())
+                .filter(|&(&field, _)| notes.departure[field])
+                .map(|(_, value)| value)
+                .product::<usize>();
+            prop_assert_eq!(solve::<Day16>(Part::Two, &print(&notes)), Ok(expected.to_string()));
+        }
+
+        #[test]
+        fn rejects_a_ticket_missing_a_value(notes in notes(), ticket in 0..6_usize) {
+            let mut notes = notes;
+            let ticket = ticket % (notes.nearby.len() + 1);
+            match ticket.checked_sub(1) {
+                Some(nearby) => notes.nearby[nearby].pop(),
+                None => notes.mine.pop(),
+            };
+            let reason = format!("expected {} values, one per field", notes.departure.len());
+            let err = parse_input::<Day16>(&print(&notes)).err();
+            prop_assert_eq!(err.map(|e| e.reason().to_owned()), Some(reason));
+        }
+    }
+
+    #[test]
+    fn should_report_columns_more_than_one_field_fits() {
+        let input = "departure a: 1-5 or 7-9\ndeparture b: 1-5 or 7-9\n\n\
+                     your ticket:\n1,2\n\n\
+                     nearby tickets:\n3,4\n";
+        let answer = solve::<Day16>(Part::Two, input).map_err(|e| e.to_string());
+        assert_eq!(
+            answer,
+            Err(String::from(
+                "day 16: can't tell which field is in columns [0, 1]"
+            ))
+        );
+    }
+}
@@ -0,0 +1,307 @@
+use aoc_common::automaton::{Automaton, Sparse};
+use aoc_common::geometry::around;
+use aoc_common::grid::{Cell, Grid2D};
+use aoc_common::render::{Frames, Paint, Render};
+use aoc_common::{ParseError, Part, Solution, SolveError};
+use serde::Deserialize;
+use std::{collections::HashSet, io, ops::RangeInclusive, str::FromStr};
+
+pub struct Day17;
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// How many cycles to boot up for.
+    pub cycles: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params { cycles: 6 }
+    }
+}
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Params = Params;
+    type Parsed = Grid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.parse()
+    }
+
+    fn part1(grid: &Self::Parsed, params: &Params) -> Result<usize, SolveError> {
+        Ok(solve(grid, 3, params.cycles))
+    }
+
+    fn part2(grid: &Self::Parsed, params: &Params) -> Result<usize, SolveError> {
+        Ok(solve(grid, 4, params.cycles))
+    }
+}
+
+impl Render for Day17 {
+    /// A frame for every cycle, each showing the x/y slice at every z from left to right and,
+    /// in four dimensions, every w from top to bottom.
+    fn render(
+        grid: &Self::Parsed,
+        params: &Params,
+        part: Part,
+        frames: &mut Frames,
+    ) -> io::Result<()> {
+        let dimensions = match part {
+            Part::One => 3,
+            Part::Two => 4,
+        };
+        let mut automaton = pocket_dimension(grid, dimensions);
+        let mut cycles = vec![automaton.live().clone()];
+        for _ in 0..params.cycles {
+            automaton.step();
+            cycles.push(automaton.live().clone());
+        }
+
+        // every frame covers every cube that's active at some point, so they're all the same size
+        let bounds = Bounds::around(cycles.iter().flatten());
+        for active in cycles.iter() {
+            frames.push(&bounds.slices(active))?;
+        }
+        Ok(())
+    }
+}
+
+/// The pocket dimension as an automaton over the active cubes.
+fn pocket_dimension(
+    grid: &Grid,
+    dimensions: usize,
+) -> Sparse<Position, impl Fn(&Position) -> Vec<Position>, impl Fn(bool, usize) -> bool> {
+    let offsets = Position::offsets(dimensions);
+    Sparse::new(
+        grid.active.iter().cloned(),
+        move |pos: &Position| pos.get_neighbouring_positions(&offsets),
+        |is_active, active_neighbours| {
+            matches!(
+                (is_active, active_neighbours),
+                (true, 2) | (true, 3) | (false, 3)
+            )
+        },
+    )
+}
+
+fn solve(grid: &Grid, dimensions: usize, cycles: usize) -> usize {
+    let mut automaton = pocket_dimension(grid, dimensions);
+    automaton.run(cycles);
+    automaton.live().len()
+}
+
+/// A cube as drawn in a frame, where `Gap` separates the slices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cube {
+    Active,
+    Inactive,
+    Gap,
+}
+
+impl Cell for Cube {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(Cube::Active),
+            '.' => Some(Cube::Inactive),
+            ' ' => Some(Cube::Gap),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Cube::Active => '#',
+            Cube::Inactive => '.',
+            Cube::Gap => ' ',
+        }
+    }
+}
+
+impl Paint for Cube {
+    fn rgb(&self) -> [u8; 3] {
+        match self {
+            Cube::Active => [40, 60, 170],
+            Cube::Inactive => [225, 230, 240],
+            Cube::Gap => [255, 255, 255],
+        }
+    }
+}
+
+/// The range of each coordinate covered by a set of cubes.
+struct Bounds {
+    w: RangeInclusive<isize>,
+    x: RangeInclusive<isize>,
+    y: RangeInclusive<isize>,
+    z: RangeInclusive<isize>,
+}
+
+impl Bounds {
+    fn around<'a>(cubes: impl Iterator<Item = &'a Position> + Clone) -> Bounds {
+        let range = |axis: fn(&Position) -> isize| {
+            let min = cubes.clone().map(axis).min().unwrap_or(0);
+            let max = cubes.clone().map(axis).max().unwrap_or(0);
+            min..=max
+        };
+        Bounds {
+            w: range(|p| p.w),
+            x: range(|p| p.x),
+            y: range(|p| p.y),
+            z: range(|p| p.z),
+        }
+    }
+
+    /// Lays out every x/y slice of `active` in a grid with a column per z and a row per w.
+    fn slices(&self, active: &HashSet<Position>) -> Grid2D<Cube> {
+        let len = |r: &RangeInclusive<isize>| (r.end() - r.start() + 1) as usize;
+        let (slice_width, slice_height) = (len(&self.x), len(&self.y));
+        let width = len(&self.z) * (slice_width + 1) - 1;
+        let height = len(&self.w) * (slice_height + 1) - 1;
+
+        Grid2D::from_fn(width, height, |(fx, fy)| {
+            let (z, x) = (fx / (slice_width + 1), fx % (slice_width + 1));
+            let (w, y) = (fy / (slice_height + 1), fy % (slice_height + 1));
+            if x == slice_width || y == slice_height {
+                return Cube::Gap;
+            }
+            let pos = Position::new(
+                self.w.start() + w as isize,
+                self.x.start() + x as isize,
+                self.y.start() + y as isize,
+                self.z.start() + z as isize,
+            );
+            match active.contains(&pos) {
+                true => Cube::Active,
+                false => Cube::Inactive,
+            }
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+/// A cube in up to four dimensions.
+pub struct Position {
+    pub w: isize,
+    pub x: isize,
+    pub y: isize,
+    pub z: isize,
+}
+
+impl Position {
+    pub fn new(w: isize, x: isize, y: isize, z: isize) -> Position {
+        Position { w, x, y, z }
+    }
+}
+
+impl Position {
+    /// The offsets to every position around one, only moving through the first `dimensions`
+    /// of z, y, x and w.
+    pub fn offsets(dimensions: usize) -> Vec<Position> {
+        around::<4>()
+            .into_iter()
+            .filter(|offset| offset[dimensions.min(4)..].iter().all(|&d| d == 0))
+            .map(|[z, y, x, w]| Position::new(w as isize, x as isize, y as isize, z as isize))
+            .collect()
+    }
+
+    /// The positions `offsets` away from this one.
+    pub fn get_neighbouring_positions(&self, offsets: &[Position]) -> Vec<Position> {
+        offsets
+            .iter()
+            .map(|d| Position {
+                w: self.w + d.w,
+                x: self.x + d.x,
+                y: self.y + d.y,
+                z: self.z + d.z,
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Grid {
+    active: HashSet<Position>,
+}
+
+impl FromStr for Grid {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut active = HashSet::new();
+        for (y, line) in s.lines().enumerate().map(|(y, line)| (y as isize, line)) {
+            for (x, (idx, c)) in line.char_indices().enumerate() {
+                let is_active = match c {
+                    '#' => true,
+                    '.' => false,
+                    _ => {
+                        return Err(ParseError::new(
+                            &line[idx..idx + c.len_utf8()],
+                            "expected `#` or `.`",
+                        ))
+                    }
+                };
+                if is_active {
+                    active.insert(Position::new(0, x as isize, y, 0));
+                }
+            }
+        }
+
+        Ok(Grid { active })
+    }
+}
+
+#[cfg(test)]
+mod day17 {
+    use super::{Day17, Position};
+    use aoc_common::{testing::answers, Part, Solution};
+    use proptest::{collection::vec, prelude::*};
+    use std::collections::HashSet;
+
+    fn slice() -> impl Strategy<Value = Vec<Vec<bool>>> {
+        (1..5_usize, 1..5_usize)
+            .prop_flat_map(|(width, height)| vec(vec(any::<bool>(), width), height))
+    }
+
+    fn print(slice: &[Vec<bool>]) -> String {
+        slice
+            .iter()
+            .map(|row| {
+                let row = row.iter().map(|&active| if active { '#' } else { '.' });
+                row.collect::<String>() + "\n"
+            })
+            .collect()
+    }
+
+    proptest! {
+        // six cycles in four dimensions are slow without optimisations
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn parses_what_it_prints(slice in slice()) {
+            let expected = slice
+                .iter()
+                .enumerate()
+                .flat_map(|(y, row)| {
+                    row.iter()
+                        .enumerate()
+                        .filter(|&(_, &active)| active)
+                        .map(move |(x, _)| Position::new(0, x as isize, y as isize, 0))
+                })
+                .collect::<HashSet<_>>();
+            prop_assert_eq!(Day17::parse(&print(&slice)).unwrap().active, expected);
+        }
+
+        #[test]
+        fn two_cubes_die_out(slice in slice()) {
+            // neither cube can ever have the two neighbours it needs to stay active, and no
+            // inactive cube can ever have the three it needs to become active
+            prop_assume!(slice.iter().flatten().filter(|&&active| active).count() <= 2);
+            let input = print(&slice);
+            let none = Ok(String::from("0"));
+            prop_assert_eq!(answers::<Day17>(&Part::ALL, &input), vec![none.clone(), none]);
+        }
+    }
+}
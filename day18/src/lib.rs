@@ -0,0 +1,219 @@
+use aoc_common::arith::Checked;
+use aoc_common::config::NoParams;
+use aoc_common::{ParseError, Solution, SolveError};
+use nom::{
+    bytes::complete::{tag, take_while1},
+    character::complete::{char, one_of},
+    combinator::{eof, map_res},
+    multi::fold_many0,
+    sequence::{delimited, pair, preceded, terminated},
+    IResult, Parser,
+};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Params = NoParams;
+    type Parsed = Vec<String>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        // both precedence rules accept the same expressions, so checking one is enough
+        input
+            .lines()
+            .map(|l| solve_pt1(l).map(|_| l.to_owned()))
+            .collect()
+    }
+
+    fn part1(lines: &Self::Parsed, _: &Self::Params) -> Result<u64, SolveError> {
+        Ok(total().sum(lines.iter().map(|l| solve_pt1(l).unwrap())))
+    }
+
+    fn part2(lines: &Self::Parsed, _: &Self::Params) -> Result<u64, SolveError> {
+        Ok(total().sum(lines.iter().map(|l| solve_pt2(l).unwrap())))
+    }
+}
+
+fn total() -> Checked {
+    Checked::new::<Day18>("summing the results")
+}
+
+fn evaluate() -> Checked {
+    Checked::new::<Day18>("evaluating an expression")
+}
+
+// AMAZING !!! https://github.com/Geal/nom/blob/master/tests/arithmetic.rs
+/// Evaluates an expression where `+` and `*` have the same precedence.
+pub fn solve_pt1(input: &str) -> Result<u64, ParseError> {
+    fn operation(input: &str) -> IResult<&str, u64> {
+        let (i, init) = paren_or_number(input)?;
+        // https://github.com/Geal/nom/blob/master/tests/arithmetic.rs#L37-L51
+        fold_many0(
+            pair(
+                delimited(char(' '), one_of("+*"), char(' ')),
+                paren_or_number,
+            ),
+            init,
+            |acc, (op, val)| match op {
+                '*' => evaluate().mul(acc, val),
+                '+' => evaluate().add(acc, val),
+                _ => unreachable!(),
+            },
+        )(i)
+    }
+
+    fn paren_or_number(input: &str) -> IResult<&str, u64> {
+        // pull out parentheses or else take the number
+        delimited(char('('), operation, char(')'))
+            .or(|l| nom_int_parse(l))
+            .parse(input)
+    }
+    let (_, out) = terminated(operation, eof)(input).map_err(expression_error)?;
+    Ok(out)
+}
+
+/// Evaluates an expression where `+` binds tighter than `*`.
+pub fn solve_pt2(input: &str) -> Result<u64, ParseError> {
+    // same as part 1 but now we split operation into 2, chaining product then sum (so we do plus first, then mul)
+
+    fn sum(input: &str) -> IResult<&str, u64> {
+        let (i, init) = paren_or_number(input)?;
+        fold_many0(preceded(tag(" + "), paren_or_number), init, |acc, val| {
+            evaluate().add(acc, val)
+        })(i)
+    }
+
+    fn product(input: &str) -> IResult<&str, u64> {
+        let (i, init) = sum(input)?;
+        fold_many0(preceded(tag(" * "), sum), init, |acc, val| {
+            evaluate().mul(acc, val)
+        })(i)
+    }
+
+    fn paren_or_number(input: &str) -> IResult<&str, u64> {
+        // pull out parentheses or else take the number
+        delimited(char('('), product, char(')'))
+            .or(|l| nom_int_parse(l))
+            .parse(input)
+    }
+    let (_, out) = terminated(product, eof)(input).map_err(expression_error)?;
+    Ok(out)
+}
+
+fn expression_error(err: nom::Err<nom::error::Error<&str>>) -> ParseError {
+    match err {
+        nom::Err::Error(e) | nom::Err::Failure(e) => {
+            ParseError::new(e.input, "expected a number, `(`, ` + ` or ` * `")
+        }
+        nom::Err::Incomplete(_) => unreachable!("complete parsers never ask for more input"),
+    }
+}
+
+fn is_decimal_digit(c: char) -> bool {
+    c.is_ascii_digit()
+}
+
+fn nom_int_parse(input: &str) -> IResult<&str, u64> {
+    map_res(take_while1(is_decimal_digit), str::parse)(input)
+}
+
+#[cfg(test)]
+mod day18 {
+    use super::{solve_pt1, solve_pt2};
+    use proptest::{collection::vec, prelude::*};
+
+    /// A number or a parenthesised expression.
+    #[derive(Clone, Debug)]
+    enum Term {
+        Number(u64),
+        Parens(Expression),
+    }
+
+    /// A term followed by any number of operators and terms.
+    #[derive(Clone, Debug)]
+    struct Expression {
+        first: Box<Term>,
+        rest: Vec<(char, Term)>,
+    }
+
+    fn expression() -> impl Strategy<Value = Expression> {
+        let number = (1..10_u64).prop_map(Term::Number);
+        let term =
+            number.prop_recursive(3, 12, 4, |term| expression_of(term).prop_map(Term::Parens));
+        expression_of(term)
+    }
+
+    fn expression_of(
+        term: impl Strategy<Value = Term> + Clone,
+    ) -> impl Strategy<Value = Expression> {
+        let op = prop_oneof![Just('+'), Just('*')];
+        (term.clone(), vec((op, term), 0..4)).prop_map(|(first, rest)| Expression {
+            first: Box::new(first),
+            rest,
+        })
+    }
+
+    fn print(expression: &Expression) -> String {
+        let term = |t: &Term| match t {
+            Term::Number(n) => n.to_string(),
+            Term::Parens(e) => format!("({})", print(e)),
+        };
+        let mut text = term(&expression.first);
+        for (op, t) in expression.rest.iter() {
+            text += &format!(" {} {}", op, term(t));
+        }
+        text
+    }
+
+    /// Evaluates left to right.
+    fn flat(expression: &Expression) -> u64 {
+        let term = |t: &Term| match t {
+            Term::Number(n) => *n,
+            Term::Parens(e) => flat(e),
+        };
+        expression
+            .rest
+            .iter()
+            .fold(term(&expression.first), |acc, (op, t)| match op {
+                '+' => acc + term(t),
+                _ => acc * term(t),
+            })
+    }
+
+    /// Evaluates every sum before multiplying them.
+    fn sums_first(expression: &Expression) -> u64 {
+        let term = |t: &Term| match t {
+            Term::Number(n) => *n,
+            Term::Parens(e) => sums_first(e),
+        };
+        let mut sums = vec![term(&expression.first)];
+        for (op, t) in expression.rest.iter() {
+            match op {
+                '+' => *sums.last_mut().unwrap() += term(t),
+                _ => sums.push(term(t)),
+            }
+        }
+        sums.iter().product()
+    }
+
+    proptest! {
+        #[test]
+        fn evaluates_left_to_right(expression in expression()) {
+            prop_assert_eq!(solve_pt1(&print(&expression)), Ok(flat(&expression)));
+        }
+
+        #[test]
+        fn evaluates_sums_first(expression in expression()) {
+            prop_assert_eq!(solve_pt2(&print(&expression)), Ok(sums_first(&expression)));
+        }
+    }
+
+    #[test]
+    fn should_blame_malformed_expressions() {
+        assert_eq!(solve_pt1("1 + (2 * ").unwrap_err().text(), " + (2 * ");
+        assert_eq!(solve_pt2("1 - 2").unwrap_err().text(), " - 2");
+    }
+}
@@ -0,0 +1,365 @@
+use aoc_common::config::NoParams;
+use aoc_common::parse::{sections, value};
+use aoc_common::{ParseError, Solution, SolveError, Unsolvable};
+use std::collections::HashMap;
+
+pub struct Day19;
+
+/// The message rules keyed by id, and the received messages.
+pub type Messages = (HashMap<usize, Rule>, Vec<String>);
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Params = NoParams;
+    type Parsed = Messages;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let mut sections = sections(input);
+        let (rules, strings) = sections
+            .next()
+            .zip(sections.next())
+            .ok_or_else(|| ParseError::new(input, "expected rules, a blank line, then messages"))?;
+
+        let rules = parse_rules(rules)?;
+        if !rules.contains_key(&0) {
+            return Err(ParseError::new(&input[..0], "missing rule 0"));
+        }
+        let strings = strings.lines().map(str::to_owned).collect();
+
+        Ok((rules, strings))
+    }
+
+    fn part1((rules, strings): &Self::Parsed, _: &Self::Params) -> Result<usize, SolveError> {
+        Ok(solve(rules, strings))
+    }
+
+    fn part2((rules, strings): &Self::Parsed, _: &Self::Params) -> Result<usize, SolveError> {
+        // the new rules loop over 42 and 31, which the input doesn't have to have
+        if let Some(id) = [42, 31].iter().find(|id| !rules.contains_key(id)) {
+            let reason = format!("part 2 loops over rule {}, which is missing", id);
+            return Err(Unsolvable::new::<Day19>(reason).into());
+        }
+        let mut rules = rules.clone();
+        rules.insert(8, Rule::parse("8: 42 | 42 8").unwrap().1);
+        rules.insert(11, Rule::parse("11: 42 31 | 42 11 31").unwrap().1);
+
+        Ok(solve(&rules, strings))
+    }
+}
+
+fn solve(rules: &HashMap<usize, Rule>, strings: &[String]) -> usize {
+    let rule_0 = rules.get(&0).unwrap();
+
+    let strings = strings
+        .iter()
+        .map(|s| (rule_0.matches(rules, s), s))
+        .collect::<Vec<_>>();
+
+    let complete_matches = strings
+        .iter()
+        .map(|(match_result, _)| match_result.iter().find(|m| m.is_empty()))
+        .filter(Option::is_some)
+        .collect::<Vec<_>>();
+
+    complete_matches.len()
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Rule {
+    Char(char),
+    Ref(usize),
+    Or(Box<Rule>, Box<Rule>),
+    And2(usize, usize),
+    And3(usize, usize, usize),
+}
+
+impl Rule {
+    fn parse(s: &str) -> Result<(usize, Rule), ParseError> {
+        let mut rule_id = 0;
+        let mut raw_rule = s;
+        if let Some((id, rule)) = s.split_once(": ") {
+            rule_id = value(id)?;
+            raw_rule = rule;
+        }
+
+        if raw_rule.starts_with('"') {
+            let mut chars = raw_rule.chars();
+            return match (chars.nth(1), chars.next(), chars.next()) {
+                (Some(c), Some('"'), None) => Ok((rule_id, Rule::Char(c))),
+                _ => Err(ParseError::new(
+                    raw_rule,
+                    "expected a single quoted character",
+                )),
+            };
+        } else if let Some((l, r)) = raw_rule.split_once(" | ") {
+            return Ok((
+                rule_id,
+                Rule::Or(Box::new(Rule::parse(l)?.1), Box::new(Rule::parse(r)?.1)),
+            ));
+        } else if raw_rule.contains(' ') {
+            let parts = raw_rule
+                .split_ascii_whitespace()
+                .map(value)
+                .collect::<Result<Vec<usize>, _>>()?;
+            return Ok((
+                rule_id,
+                match parts.len() {
+                    3 => Rule::And3(parts[0], parts[1], parts[2]),
+                    2 => Rule::And2(parts[0], parts[1]),
+                    _ => return Err(ParseError::new(raw_rule, "expected two or three rules")),
+                },
+            ));
+        }
+        Ok((rule_id, Rule::Ref(value(raw_rule)?)))
+    }
+
+    /// Every remainder of `input` left after matching this rule against its start.
+    pub fn matches<'a>(&self, all_rules: &HashMap<usize, Rule>, input: &'a str) -> Vec<&'a str> {
+        if input.is_empty() {
+            return vec![];
+        }
+
+        match self {
+            Rule::Ref(r) => all_rules.get(r).unwrap().matches(all_rules, input),
+            Rule::Char(value) => match input.chars().nth(0).unwrap() == *value {
+                true => vec![&input[1..]],
+                false => vec![],
+            },
+            Rule::Or(l, r) => {
+                let mut res = Vec::new();
+                res.append(&mut l.matches(all_rules, input));
+                res.append(&mut r.matches(all_rules, input));
+                res
+            }
+            Rule::And2(a, b) => {
+                let mut res = Vec::new();
+                for i in all_rules.get(a).unwrap().matches(all_rules, input) {
+                    for j in all_rules.get(b).unwrap().matches(all_rules, i) {
+                        res.push(j);
+                    }
+                }
+                res
+            }
+            Rule::And3(a, b, c) => {
+                let mut res = Vec::new();
+                for i in all_rules.get(a).unwrap().matches(all_rules, input) {
+                    for j in all_rules.get(b).unwrap().matches(all_rules, i) {
+                        for k in all_rules.get(c).unwrap().matches(all_rules, j) {
+                            res.push(k);
+                        }
+                    }
+                }
+                res
+            }
+        }
+    }
+}
+
+/// Parses the rules section, checking every rule referred to exists.
+pub fn parse_rules(input: &str) -> Result<HashMap<usize, Rule>, ParseError> {
+    let rules = input
+        .lines()
+        .map(Rule::parse)
+        .collect::<Result<HashMap<_, _>, _>>()?;
+
+    // make sure every rule that's referred to exists
+    for line in input.lines() {
+        let body = line.split_once(": ").map_or(line, |(_, body)| body);
+        for id in body
+            .split_ascii_whitespace()
+            .filter(|t| !t.starts_with('"'))
+        {
+            if id != "|" && !rules.contains_key(&value(id)?) {
+                return Err(ParseError::new(id, "no rule with this id"));
+            }
+        }
+    }
+
+    Ok(rules)
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::bool_assert_comparison,
+    clippy::len_zero,
+    clippy::redundant_pattern_matching
+)]
+mod day19 {
+    use super::parse_rules;
+    use super::{Day19, Rule};
+    use aoc_common::{parses_what_it_prints, solve, Part};
+    use proptest::{collection::vec, prelude::*};
+    use std::collections::HashMap;
+
+    /// A rule made of references to other rules.
+    fn sequence() -> impl Strategy<Value = Rule> {
+        prop_oneof![
+            (0..200_usize).prop_map(Rule::Ref),
+            (0..200_usize, 0..200_usize).prop_map(|(a, b)| Rule::And2(a, b)),
+            (0..200_usize, 0..200_usize, 0..200_usize).prop_map(|(a, b, c)| Rule::And3(a, b, c)),
+        ]
+    }
+
+    fn rule() -> impl Strategy<Value = Rule> {
+        prop_oneof![
+            prop::char::range('a', 'z').prop_map(Rule::Char),
+            sequence(),
+            (sequence(), sequence()).prop_map(|(l, r)| Rule::Or(Box::new(l), Box::new(r))),
+        ]
+    }
+
+    fn print(rule: &Rule) -> String {
+        match rule {
+            Rule::Char(c) => format!("\"{}\"", c),
+            Rule::Ref(r) => r.to_string(),
+            Rule::Or(l, r) => format!("{} | {}", print(l), print(r)),
+            Rule::And2(a, b) => format!("{} {}", a, b),
+            Rule::And3(a, b, c) => format!("{} {} {}", a, b, c),
+        }
+    }
+
+    /// Rules where `0` matches exactly `ab` and `ba`, followed by `messages`.
+    fn notes(messages: &[String]) -> String {
+        let rules = "0: 1 2 | 2 1\n1: \"a\"\n2: \"b\"\n";
+        format!("{}\n{}", rules, messages.join("\n") + "\n")
+    }
+
+    parses_what_it_prints!((0..200_usize, rule()) => |(id, rule): &(usize, Rule)| {
+        format!("{}: {}", id, print(rule))
+    }, Rule::parse);
+
+    proptest! {
+        #[test]
+        fn counts_complete_matches(messages in vec("[ab]{1,4}", 1..20)) {
+            let expected = messages.iter().filter(|&m| m == "ab" || m == "ba").count();
+            prop_assert_eq!(solve::<Day19>(Part::One, &notes(&messages)), Ok(expected.to_string()));
+        }
+    }
+
+    fn test(rules: &HashMap<usize, Rule>, idx: usize, str: &str) -> bool {
+        let res = rules.get(&idx).unwrap().matches(rules, str);
+        match res.iter().find(|r| r.len() == 0) {
+            Some(_) => true,
+            None => false,
+        }
+    }
+
+    #[test]
+    fn example_pt1_1() {
+        let rules = parse_rules("0: 1 2\n1: \"a\"\n2: 1 3 | 3 1\n3: \"b\"").unwrap();
+
+        assert_eq!(test(&rules, 0, "aab"), true);
+        assert_eq!(test(&rules, 0, "aba"), true);
+        assert_eq!(test(&rules, 0, "baa"), false);
+    }
+
+    #[test]
+    fn and3_test() {
+        let rules = parse_rules("0: 1 1 1\n1: \"a\"").unwrap();
+        assert_eq!(test(&rules, 0, "aaa"), true);
+        assert_eq!(test(&rules, 0, "aba"), false);
+        assert_eq!(test(&rules, 0, "aab"), false);
+        assert_eq!(test(&rules, 0, "baa"), false);
+        assert_eq!(test(&rules, 0, "aa"), false);
+        assert_eq!(test(&rules, 0, "aaaa"), false);
+        assert_eq!(test(&rules, 0, "aaab"), false);
+    }
+
+    #[test]
+    fn and2_test() {
+        let rules = parse_rules("0: 1 1\n1: \"a\"").unwrap();
+        assert_eq!(test(&rules, 0, "aa"), true);
+        assert_eq!(test(&rules, 0, "ab"), false);
+        assert_eq!(test(&rules, 0, "ba"), false);
+        assert_eq!(test(&rules, 0, "aaa"), false);
+        assert_eq!(test(&rules, 0, "aab"), false);
+        assert_eq!(test(&rules, 0, "a"), false);
+        assert_eq!(test(&rules, 0, "b"), false);
+    }
+
+    #[test]
+    fn or_test() {
+        let rules = parse_rules("0: 1 1 | 2 2\n1: \"a\"\n2: \"b\"").unwrap();
+        // assert_eq!(test(&rules, 0, "aa"), true);
+        assert_eq!(test(&rules, 0, "bb"), true);
+        assert_eq!(test(&rules, 0, "ab"), false);
+        assert_eq!(test(&rules, 0, "ba"), false);
+        assert_eq!(test(&rules, 0, "aaa"), false);
+        assert_eq!(test(&rules, 0, "aab"), false);
+        assert_eq!(test(&rules, 0, "a"), false);
+        assert_eq!(test(&rules, 0, "b"), false);
+    }
+
+    #[test]
+    fn should_parse_char_rule() {
+        let rule = "0: \"a\"";
+        let (id, rule) = Rule::parse(rule).unwrap();
+        assert_eq!(id, 0);
+        assert_eq!(rule, Rule::Char('a'))
+    }
+
+    #[test]
+    fn should_parse_ref_rule() {
+        let rule = "0: 2";
+        let (id, rule) = Rule::parse(rule).unwrap();
+        assert_eq!(id, 0);
+        assert_eq!(rule, Rule::Ref(2))
+    }
+
+    #[test]
+    fn should_parse_or_rule() {
+        let rule = "0: 1 2 | 2 1";
+        let (id, rule) = Rule::parse(rule).unwrap();
+        assert_eq!(id, 0);
+        assert_eq!(
+            rule,
+            Rule::Or(Box::new(Rule::And2(1, 2)), Box::new(Rule::And2(2, 1)))
+        )
+    }
+
+    #[test]
+    fn should_parse_and_rule() {
+        let rule = "0: 1 2";
+        let (id, rule) = Rule::parse(rule).unwrap();
+        assert_eq!(id, 0);
+        assert_eq!(rule, Rule::And2(1, 2));
+    }
+
+    #[test]
+    fn should_parse_and3_rule() {
+        let rule = "0: 1 2 3";
+        let (id, rule) = Rule::parse(rule).unwrap();
+        assert_eq!(id, 0);
+        assert_eq!(rule, Rule::And3(1, 2, 3));
+    }
+
+    #[test]
+    fn should_parse_combination_rule() {
+        let rule = "0: 1 2 | 2 1";
+        let (id, rule) = Rule::parse(rule).unwrap();
+        assert_eq!(id, 0);
+        assert_eq!(
+            rule,
+            Rule::Or(Box::new(Rule::And2(1, 2)), Box::new(Rule::And2(2, 1)))
+        )
+    }
+
+    #[test]
+    fn should_report_rules_part2_loops_over_missing() {
+        let input = "0: 8 11\n8: 42\n11: 42 31\n42: \"a\"\n31: \"b\"\n\naab\n";
+        assert_eq!(solve::<Day19>(Part::Two, input), Ok(String::from("1")));
+
+        let input = "0: 1\n1: \"a\"\n\na\n";
+        assert_eq!(solve::<Day19>(Part::One, input), Ok(String::from("1")));
+        let answer = solve::<Day19>(Part::Two, input).map_err(|e| e.to_string());
+        assert_eq!(
+            answer,
+            Err(String::from(
+                "day 19: part 2 loops over rule 42, which is missing"
+            ))
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use aoc_common::config::NoParams;
+use aoc_common::parse::value;
+use aoc_common::{ParseError, Solution, SolveError};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref PARSINGREGEX: Regex = Regex::new(r"^(\d+)-(\d+) (.): (.+)$").unwrap();
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Params = NoParams;
+    type Parsed = Vec<Password>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.lines().map(Password::new).collect()
+    }
+
+    fn part1(passwords: &Self::Parsed, _: &Self::Params) -> Result<usize, SolveError> {
+        Ok(passwords
+            .iter()
+            .filter(|p| p.followed_corporate_policy())
+            .count())
+    }
+
+    fn part2(passwords: &Self::Parsed, _: &Self::Params) -> Result<usize, SolveError> {
+        Ok(passwords
+            .iter()
+            .filter(|p| p.followed_revised_corporate_policy())
+            .count())
+    }
+}
+
+#[derive(Debug)]
+pub struct Password {
+    password: String,
+    min: usize,
+    max: usize,
+    character: char,
+}
+
+impl Password {
+    fn new(line: &str) -> Result<Self, ParseError> {
+        let captures = PARSINGREGEX
+            .captures(line)
+            .ok_or_else(|| ParseError::new(line, "expected `min-max char: password`"))?;
+        let (min, max) = (captures.get(1).unwrap(), captures.get(2).unwrap());
+        let password = captures.get(4).unwrap().as_str();
+
+        let password = Password {
+            min: value(min.as_str())?,
+            max: value(max.as_str())?,
+            character: captures[3].chars().next().unwrap(),
+            password: password.to_string(),
+        };
+        // the revised policy treats these as 1-based positions in the password
+        if password.min == 0 {
+            return Err(ParseError::new(min.as_str(), "positions start at 1"));
+        }
+        if password.max > password.password.chars().count() {
+            return Err(ParseError::new(
+                max.as_str(),
+                "position is past the end of the password",
+            ));
+        }
+
+        Ok(password)
+    }
+
+    /// Whether the character occurs between `min` and `max` times.
+    pub fn followed_corporate_policy(&self) -> bool {
+        let count = self.password.matches(self.character).count();
+        count >= self.min && count <= self.max
+    }
+
+    /// Whether the character is at exactly one of the 1-based positions `min` and `max`.
+    pub fn followed_revised_corporate_policy(&self) -> bool {
+        let a = self.password.chars().nth(self.min - 1).unwrap();
+        let b = self.password.chars().nth(self.max - 1).unwrap();
+
+        (a == self.character && b != self.character) || (a != self.character && b == self.character)
+    }
+}
+
+#[cfg(test)]
+mod day2 {
+    use super::Password;
+    use aoc_common::parses_what_it_prints;
+    use proptest::{prelude::*, sample::Index};
+
+    /// A password with its policy's `min`, `max` and character, where both are valid positions
+    /// in the password.
+    fn password() -> impl Strategy<Value = (usize, usize, char, String)> {
+        ("[a-e]{1,12}", any::<Index>(), any::<Index>(), "[a-e]").prop_map(
+            |(password, min, max, character)| {
+                let min = min.index(password.len()) + 1;
+                let max = min + max.index(password.len() - min + 1);
+                (min, max, character.chars().next().unwrap(), password)
+            },
+        )
+    }
+
+    fn print((min, max, character, password): &(usize, usize, char, String)) -> String {
+        format!("{}-{} {}: {}", min, max, character, password)
+    }
+
+    parses_what_it_prints!(password() => print, |line| {
+        Password::new(line).map(|p| (p.min, p.max, p.character, p.password))
+    });
+}
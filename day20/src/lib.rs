@@ -0,0 +1,444 @@
+use aoc_common::config::NoParams;
+use aoc_common::explain::Explain;
+use aoc_common::grid::{Cell, Grid2D};
+use aoc_common::parse::{sections, value};
+use aoc_common::render::{Frames, Paint, Render};
+use aoc_common::{ParseError, Part, Solution, SolveError, Unsolvable};
+use std::collections::HashMap;
+use std::io;
+use std::str::FromStr;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Params = NoParams;
+    type Parsed = Vec<Tile>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        get_tiles(input)
+    }
+
+    fn part1(tiles: &Self::Parsed, _: &Self::Params) -> Result<usize, SolveError> {
+        let (_, product) = part1(tiles).map_err(|()| no_corners())?;
+        Ok(product)
+    }
+
+    fn part2(tiles: &Self::Parsed, _: &Self::Params) -> Result<usize, SolveError> {
+        let (corners, _) = part1(tiles).map_err(|()| no_corners())?;
+        part2(tiles, &corners)
+            .map_err(|()| Unsolvable::new::<Day20>("failed to assemble the image").into())
+    }
+}
+
+impl Render for Day20 {
+    /// Part 1 draws the arranged tiles with their borders, part 2 the image without them and
+    /// then the image turned to find the sea monsters, with the monsters picked out.
+    fn render(
+        tiles: &Self::Parsed,
+        _: &Self::Params,
+        part: Part,
+        frames: &mut Frames,
+    ) -> io::Result<()> {
+        let (corners, _) = part1(tiles).expect("failed to find the corner tiles");
+        let grid = arrange(tiles, &corners).expect("failed to assemble the image");
+        match part {
+            Part::One => {
+                let tiles = stitch(&grid, |t| t.pixels.clone()).expect("missing a tile");
+                frames.push(&tiles.pixels)?;
+            }
+            Part::Two => {
+                let image = grid_to_tile(&grid).expect("missing a tile");
+                frames.push(&image.pixels)?;
+                frames.push(&mark_sea_monsters(&image).expect("failed to find a sea monster"))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Paint for Pixel {
+    fn rgb(&self) -> [u8; 3] {
+        match self {
+            Pixel::Calm => [20, 60, 130],
+            Pixel::Rough => [200, 220, 240],
+            Pixel::Monster => [20, 140, 40],
+        }
+    }
+}
+
+fn no_corners() -> Unsolvable {
+    Unsolvable::new::<Day20>("expected exactly four tiles to be corners")
+}
+
+/// The corner tiles, the ones matching on just two adjacent sides, and their ids multiplied.
+fn part1(tiles: &[Tile]) -> Result<(Vec<&Tile>, usize), ()> {
+    let explain = Explain::new::<Day20>("finding corners");
+    let mut corners = Vec::new();
+
+    for tile in tiles.iter() {
+        let (mut n, mut s, mut e, mut w) = (false, false, false, false);
+        for other in tiles.iter().filter(|t| t.id != tile.id) {
+            match (
+                tile.matches_north(other).is_some(),
+                tile.matches_south(other).is_some(),
+                tile.matches_east(other).is_some(),
+                tile.matches_west(other).is_some(),
+            ) {
+                (true, _, _, _) => n = true,
+                (_, true, _, _) => s = true,
+                (_, _, true, _) => e = true,
+                (_, _, _, true) => w = true,
+                _ => {}
+            }
+        }
+        match (n, s, e, w) {
+            (false, true, true, false)
+            | (false, true, false, true)
+            | (true, false, true, false)
+            | (true, false, false, true) => {
+                explain.note(|| {
+                    let sides = [(n, "north"), (s, "south"), (e, "east"), (w, "west")];
+                    let matched = sides.iter().filter(|(m, _)| *m).map(|&(_, side)| side);
+                    format!(
+                        "tile {} only matches on its {} sides",
+                        tile.id,
+                        matched.collect::<Vec<_>>().join(" and ")
+                    )
+                });
+                corners.push(tile)
+            }
+            _ => {}
+        };
+    }
+    if corners.len() != 4 {
+        return Err(());
+    }
+    Ok((
+        corners.clone(),
+        corners.iter().map(|c| c.id).product::<usize>(),
+    ))
+}
+
+fn filter_tiles(tiles: &[Tile], unwanted_id: usize) -> impl Iterator<Item = &Tile> {
+    tiles.iter().filter(move |t| t.id != unwanted_id)
+}
+
+fn part2(tiles: &[Tile], corners: &[&Tile]) -> Result<usize, ()> {
+    let image = mark_sea_monsters(&grid_to_tile(&arrange(tiles, corners)?)?)?;
+    Ok(image.cells().filter(|&&p| p == Pixel::Rough).count())
+}
+
+/// Lays the tiles out with the first corner at the top left.
+fn arrange(tiles: &[Tile], corners: &[&Tile]) -> Result<Grid, ()> {
+    Explain::new::<Day20>("arranging tiles")
+        .note(|| format!("tile {} goes in the top left", corners[0].id));
+    corners[0]
+        .get_orientations()
+        .iter()
+        .filter(|o| filter_tiles(tiles, corners[0].id).any(|t| o.matches_south(t).is_some()))
+        .filter(|o| filter_tiles(tiles, corners[0].id).any(|t| o.matches_west(t).is_some()))
+        .map(|t| get_grid(tiles, t))
+        .find(Result::is_ok)
+        .map(|grid| grid.unwrap())
+        .ok_or(())
+}
+
+/// Turns the image until it has sea monsters in, and marks them.
+fn mark_sea_monsters(grid: &Tile) -> Result<Grid2D<Pixel>, ()> {
+    // we're cheeky and use a tile so we can orient it easier using our methods from pt10
+    let sea_monster = [
+        "                  #",
+        "#    ##    ##    ###",
+        " #  #  #  #  #  #",
+    ];
+    let (monster_width, monster_height) = (sea_monster[1].len(), sea_monster.len());
+
+    let sea_monster_rules: Vec<_> = sea_monster
+        .iter()
+        .enumerate()
+        .flat_map(|(y, &line)| {
+            line.chars()
+                .enumerate()
+                .filter(move |(_, c)| '#' == *c)
+                .map(move |(x, _)| (x, y))
+        })
+        .collect();
+
+    for mut image in grid.pixels.orientations() {
+        let mut count = 0;
+
+        // an image smaller than a sea monster can't have one in
+        let last_x = image.width().checked_sub(monster_width);
+        let last_y = image.height().checked_sub(monster_height);
+        let (last_x, last_y) = match last_x.zip(last_y) {
+            Some(last) => last,
+            None => continue,
+        };
+
+        for x in 0..=last_x {
+            for y in 0..=last_y {
+                let found_sea_monster = sea_monster_rules
+                    .iter()
+                    .all(|(dx, dy)| image[(x + dx, y + dy)] == Pixel::Rough);
+
+                if found_sea_monster {
+                    for &(dx, dy) in sea_monster_rules.iter() {
+                        image[(x + dx, y + dy)] = Pixel::Monster;
+                    }
+                    count += 1;
+                }
+            }
+        }
+
+        if count > 0 {
+            return Ok(image);
+        }
+    }
+    Err(())
+}
+
+type Grid = HashMap<(usize, usize), Tile>;
+fn get_grid(tiles: &[Tile], top_left: &Tile) -> Result<Grid, ()> {
+    let mut grid = HashMap::new();
+    grid.insert((0, 0), top_left.clone());
+
+    let grid_size = (tiles.len() as f64).sqrt() as usize;
+
+    // iterate columns and fill those out too!
+    for x in 0..grid_size {
+        if x != 0 {
+            let up_left = grid.get(&(x - 1, 0)).ok_or(())?;
+            let found = filter_tiles(tiles, up_left.id)
+                .map(|t| up_left.matches_west(t))
+                .find(Option::is_some)
+                .map(|t| t.unwrap())
+                .ok_or(())?;
+
+            grid.insert((x, 0), found);
+        }
+
+        for y in 1..grid_size {
+            let above = grid.get(&(x, y - 1)).ok_or(())?;
+            let found = filter_tiles(tiles, above.id)
+                .map(|t| above.matches_south(t))
+                .find(Option::is_some)
+                .map(|t| t.unwrap())
+                .ok_or(())?;
+
+            grid.insert((x, y), found);
+        }
+    }
+
+    Ok(grid)
+}
+
+fn grid_to_tile(grid: &Grid) -> Result<Tile, ()> {
+    stitch(grid, Tile::without_borders)
+}
+
+/// Joins the part of each tile picked out by `piece` into one image.
+fn stitch(grid: &Grid, piece: impl Fn(&Tile) -> Grid2D<Pixel>) -> Result<Tile, ()> {
+    let inner = grid
+        .iter()
+        .map(|(&pos, tile)| (pos, piece(tile)))
+        .collect::<HashMap<_, _>>();
+    let tile_size = inner.values().next().ok_or(())?.width();
+    let grid_size = (grid.values().len() as f32).sqrt() as usize;
+
+    for y in 0..grid_size {
+        for x in 0..grid_size {
+            inner.get(&(x, y)).ok_or(())?;
+        }
+    }
+    let image_size = grid_size * tile_size;
+    let pixels = Grid2D::from_fn(image_size, image_size, |(x, y)| {
+        inner[&(x / tile_size, y / tile_size)][(x % tile_size, y % tile_size)]
+    });
+
+    Ok(Tile { id: 0, pixels })
+}
+
+fn get_tiles(input: &str) -> Result<Vec<Tile>, ParseError> {
+    sections(input).map(str::parse).collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pixel {
+    Calm,
+    Rough,
+    Monster,
+}
+
+impl Cell for Pixel {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Pixel::Calm),
+            '#' => Some(Pixel::Rough),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Pixel::Calm => '.',
+            Pixel::Rough => '#',
+            Pixel::Monster => 'O',
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Tile {
+    id: usize,
+    pixels: Grid2D<Pixel>,
+}
+
+impl FromStr for Tile {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (header, pixels) = s.split_once('\n').unwrap_or((s, ""));
+
+        let id = header
+            .strip_prefix("Tile ")
+            .and_then(|h| h.strip_suffix(':'))
+            .ok_or_else(|| ParseError::new(header, "expected `Tile <id>:`"))?;
+        let pixels = Grid2D::parse(pixels)?;
+        if pixels.width() < 3 || pixels.width() != pixels.height() {
+            return Err(ParseError::new(
+                s,
+                "expected a square tile at least 3 pixels wide",
+            ));
+        }
+
+        Ok(Tile {
+            id: value(id)?,
+            pixels,
+        })
+    }
+}
+
+impl Tile {
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    fn get_borders(&self) -> Vec<Vec<Pixel>> {
+        let (width, height) = (self.pixels.width(), self.pixels.height());
+        vec![
+            self.pixels.row(0).to_vec(),
+            self.pixels.row(height - 1).to_vec(),
+            self.pixels.column(0).copied().collect(),
+            self.pixels.column(width - 1).copied().collect(),
+        ]
+    }
+
+    fn get_orientations(&self) -> Vec<Tile> {
+        self.pixels
+            .orientations()
+            .into_iter()
+            .map(|pixels| Tile {
+                id: self.id,
+                pixels,
+            })
+            .collect()
+    }
+
+    fn matches_on_border(&self, other: &Tile, idx: usize, other_idx: usize) -> Option<Tile> {
+        let b = self.get_borders();
+        for orientation in other.get_orientations().iter() {
+            if b[idx] == orientation.get_borders()[other_idx] {
+                return Some(orientation.clone());
+            }
+        }
+        None
+    }
+
+    fn matches_north(&self, other: &Tile) -> Option<Tile> {
+        self.matches_on_border(other, 0, 1)
+    }
+
+    fn matches_south(&self, other: &Tile) -> Option<Tile> {
+        self.matches_on_border(other, 1, 0)
+    }
+
+    fn matches_west(&self, other: &Tile) -> Option<Tile> {
+        self.matches_on_border(other, 3, 2)
+    }
+
+    fn matches_east(&self, other: &Tile) -> Option<Tile> {
+        self.matches_on_border(other, 2, 3)
+    }
+
+    fn without_borders(&self) -> Grid2D<Pixel> {
+        let (width, height) = (self.pixels.width(), self.pixels.height());
+        self.pixels.crop((1, 1), width - 2, height - 2)
+    }
+}
+
+#[cfg(test)]
+mod day20 {
+    use super::{mark_sea_monsters, Day20, Tile};
+    use aoc_common::{solve, Part, Solution};
+    use proptest::{collection::vec, prelude::*};
+
+    /// Square tiles of the same size, each with an id and its rows of pixels.
+    fn tiles() -> impl Strategy<Value = Vec<(usize, Vec<String>)>> {
+        (3..8_usize).prop_flat_map(|size| {
+            let row = vec(prop_oneof![Just('.'), Just('#')], size)
+                .prop_map(|row| row.into_iter().collect::<String>());
+            vec((1000..10_000_usize, vec(row, size)), 1..5)
+        })
+    }
+
+    fn print(tiles: &[(usize, Vec<String>)]) -> String {
+        let tiles = tiles
+            .iter()
+            .map(|(id, rows)| format!("Tile {}:\n{}\n", id, rows.join("\n")))
+            .collect::<Vec<_>>();
+        tiles.join("\n")
+    }
+
+    proptest! {
+        #[test]
+        fn parses_what_it_prints(tiles in tiles()) {
+            let parsed = Day20::parse(&print(&tiles)).unwrap();
+            let parsed = parsed
+                .iter()
+                .map(|t| (t.id(), t.pixels.to_string().lines().map(str::to_owned).collect()))
+                .collect::<Vec<_>>();
+            prop_assert_eq!(parsed, tiles);
+        }
+
+        #[test]
+        fn ignores_the_trailing_newline(tiles in tiles()) {
+            let input = print(&tiles);
+            prop_assert_eq!(Day20::parse(&input), Day20::parse(input.trim_end()));
+        }
+    }
+
+    #[test]
+    fn finds_no_sea_monster_in_a_smaller_image() {
+        let image = "Tile 1:\n#####\n#####\n#####\n#####\n#####"
+            .parse::<Tile>()
+            .unwrap();
+        assert_eq!(mark_sea_monsters(&image), Err(()));
+    }
+
+    #[test]
+    fn should_report_tiles_without_four_corners() {
+        let input = "Tile 1:\n#.#\n...\n#.#\n";
+        for &part in Part::ALL.iter() {
+            let answer = solve::<Day20>(part, input).map_err(|e| e.to_string());
+            assert_eq!(
+                answer,
+                Err(String::from(
+                    "day 20: expected exactly four tiles to be corners"
+                ))
+            );
+        }
+    }
+}
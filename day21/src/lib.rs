@@ -0,0 +1,241 @@
+use aoc_common::config::NoParams;
+use aoc_common::explain::Explain;
+use aoc_common::graph::bipartite_matching;
+use aoc_common::{ParseError, Solution, SolveError, Unsolvable};
+use std::collections::BTreeSet;
+use std::str::FromStr;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Params = NoParams;
+    type Parsed = Vec<Food>;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.lines().map(str::parse).collect()
+    }
+
+    fn part1(foods: &Self::Parsed, _: &Self::Params) -> Result<usize, SolveError> {
+        Ok(part1(foods)?)
+    }
+
+    fn part2(foods: &Self::Parsed, _: &Self::Params) -> Result<String, SolveError> {
+        Ok(part2(foods)?)
+    }
+}
+
+fn part1(foods: &[Food]) -> Result<usize, Unsolvable> {
+    let solved_allergens = solve_allergens(foods)?;
+
+    let known_ing = solved_allergens
+        .iter()
+        .map(|s| s.0.clone())
+        .collect::<Vec<_>>();
+
+    Ok(foods
+        .iter()
+        .flat_map(|f| f.ingredients.clone())
+        .filter(|i| !known_ing.contains(i))
+        .count())
+}
+
+fn part2(foods: &[Food]) -> Result<String, Unsolvable> {
+    let mut solved_allergens = solve_allergens(foods)?;
+    solved_allergens.sort_by_key(|(_, a)| a.clone());
+
+    Ok(solved_allergens
+        .iter()
+        .map(|(i, _)| i.as_str())
+        .collect::<Vec<_>>()
+        .join(","))
+}
+
+/// Pairs each allergen with the ingredient that contains it, as `(ingredient, allergen)`, or
+/// says which allergens the foods don't pin down.
+pub fn solve_allergens(foods: &[Food]) -> Result<Vec<(String, String)>, Unsolvable> {
+    let allergens = foods
+        .iter()
+        .flat_map(|f| f.allergens.iter())
+        .collect::<BTreeSet<_>>();
+
+    // an allergen can only be in the ingredients every food listing it has in common
+    let candidates = allergens
+        .into_iter()
+        .map(|allergen| {
+            let listing = foods
+                .iter()
+                .filter(|f| f.allergens.contains(allergen))
+                .collect::<Vec<_>>();
+            let common = listing[0]
+                .ingredients
+                .iter()
+                .filter(|&i| listing.iter().all(|f| f.ingredients.contains(i)))
+                .collect::<Vec<_>>();
+            (allergen, common)
+        })
+        .collect::<Vec<_>>();
+    let matching = bipartite_matching(candidates.iter().cloned());
+    if !matching.is_unique() {
+        let allergens = matching.unmatched.iter().chain(matching.ambiguous.iter());
+        let reason = format!(
+            "can't tell which ingredient contains {:?}",
+            allergens.collect::<Vec<_>>()
+        );
+        return Err(Unsolvable::new::<Day21>(reason));
+    }
+
+    let explain = Explain::new::<Day21>("mapping allergens");
+    for (allergen, ingredient) in matching.pairs.iter() {
+        explain.note(|| {
+            let (_, common) = candidates.iter().find(|(a, _)| a == allergen).unwrap();
+            let common = common.iter().map(|i| i.as_str()).collect::<Vec<_>>();
+            format!(
+                "{} is in {}, out of {}",
+                allergen,
+                ingredient,
+                common.join(", ")
+            )
+        });
+    }
+
+    Ok(matching
+        .pairs
+        .into_iter()
+        .map(|(allergen, ingredient)| (ingredient.clone(), allergen.clone()))
+        .collect())
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Food {
+    pub ingredients: Vec<String>,
+    pub allergens: Vec<String>,
+}
+
+impl FromStr for Food {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (ingredients, full_allergens) = s
+            .split_once(" (contains ")
+            .and_then(|(i, a)| Some((i, a.strip_suffix(')')?)))
+            .ok_or_else(|| ParseError::new(s, "expected `ingredients (contains allergens)`"))?;
+        let ingredients = ingredients
+            .split_ascii_whitespace()
+            .map(|s| s.to_owned())
+            .collect();
+        let allergens = full_allergens
+            .trim()
+            .split(", ")
+            .map(|s| s.to_owned())
+            .collect();
+
+        Ok(Food {
+            ingredients,
+            allergens,
+        })
+    }
+}
+
+#[cfg(test)]
+mod day21 {
+    use super::{Day21, Food};
+    use aoc_common::{parses_what_it_prints, solve, Part, Solution};
+    use proptest::{collection::vec, prelude::*, sample::subsequence};
+
+    const ALLERGENS: [&str; 8] = [
+        "dairy",
+        "eggs",
+        "fish",
+        "nuts",
+        "peanuts",
+        "sesame",
+        "shellfish",
+        "soy",
+    ];
+
+    fn food() -> impl Strategy<Value = Food> {
+        let names = || vec("[a-z]{2,8}", 1..6);
+        (names(), names()).prop_map(|(ingredients, allergens)| Food {
+            ingredients,
+            allergens,
+        })
+    }
+
+    fn print(foods: &[Food]) -> String {
+        foods
+            .iter()
+            .map(|f| {
+                format!(
+                    "{} (contains {})\n",
+                    f.ingredients.join(" "),
+                    f.allergens.join(", ")
+                )
+            })
+            .collect()
+    }
+
+    /// Foods where `danger<i>` contains allergen `i`, along with the number of times a safe
+    /// ingredient is listed. Allergen `i` is listed on two foods that each also contain
+    /// `danger0` to `danger<i - 1>`, so the allergens can be placed one at a time starting
+    /// from the last, and every safe ingredient is only on one food.
+    fn puzzle() -> impl Strategy<Value = (Vec<Food>, Vec<&'static str>, usize)> {
+        subsequence(ALLERGENS.to_vec(), 1..=ALLERGENS.len())
+            .prop_shuffle()
+            .prop_flat_map(|allergens| {
+                let safe = vec(0..3_usize, allergens.len() * 2);
+                (Just(allergens), safe)
+            })
+            .prop_map(|(allergens, safe)| {
+                let foods = safe
+                    .iter()
+                    .enumerate()
+                    .map(|(food, &safe)| {
+                        let listed = food / 2 + 1;
+                        let dangerous = (0..listed).map(|i| format!("danger{}", i));
+                        let safe = (0..safe).map(|i| format!("safe{}x{}", food, i));
+                        Food {
+                            ingredients: dangerous.chain(safe).collect(),
+                            allergens: allergens[..listed].iter().map(|&a| a.to_owned()).collect(),
+                        }
+                    })
+                    .collect();
+                (foods, allergens, safe.iter().sum())
+            })
+    }
+
+    parses_what_it_prints!(vec(food(), 1..10) => |f: &Vec<_>| print(f), Day21::parse);
+
+    proptest! {
+        #[test]
+        fn counts_the_safe_ingredients((foods, _, safe) in puzzle()) {
+            prop_assert_eq!(solve::<Day21>(Part::One, &print(&foods)), Ok(safe.to_string()));
+        }
+
+        #[test]
+        fn lists_the_dangerous_ingredients_by_allergen((foods, allergens, _) in puzzle()) {
+            let mut dangerous = allergens
+                .iter()
+                .enumerate()
+                .map(|(i, allergen)| (allergen, format!("danger{}", i)))
+                .collect::<Vec<_>>();
+            dangerous.sort();
+            let expected = dangerous.into_iter().map(|(_, i)| i).collect::<Vec<_>>().join(",");
+            prop_assert_eq!(solve::<Day21>(Part::Two, &print(&foods)), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn should_report_allergens_more_than_one_ingredient_could_contain() {
+        let answer = solve::<Day21>(Part::Two, "kfcds mxmxvkd (contains dairy)\n");
+        assert_eq!(
+            answer.map_err(|e| e.to_string()),
+            Err(String::from(
+                "day 21: can't tell which ingredient contains [\"dairy\"]"
+            ))
+        );
+    }
+}
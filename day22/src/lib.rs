@@ -0,0 +1,238 @@
+use aoc_common::config::NoParams;
+use aoc_common::parse::{sections, value};
+use aoc_common::progress::Task;
+use aoc_common::{ParseError, Solution, SolveError};
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+pub struct Day22;
+
+/// The decks of player 1 and player 2, top card first.
+pub type Hands = (VecDeque<usize>, VecDeque<usize>);
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+
+    type Params = NoParams;
+    type Parsed = Hands;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_hands(input)
+    }
+
+    fn part1(hands: &Self::Parsed, _: &Self::Params) -> Result<usize, SolveError> {
+        Ok(pt1(
+            hands.clone(),
+            &mut Task::unbounded::<Day22>("playing combat"),
+        ))
+    }
+
+    fn part2(hands: &Self::Parsed, _: &Self::Params) -> Result<usize, SolveError> {
+        Ok(pt2(
+            hands.clone(),
+            &mut Task::unbounded::<Day22>("playing recursive combat"),
+        )
+        .1)
+    }
+}
+
+/// Plays a game of combat, returning the winner's score, ticking `task` for every round. The game
+/// can go on forever, so stopping it with the task gives the higher score so far.
+fn pt1(hands: Hands, task: &mut Task) -> usize {
+    let (mut p1, mut p2) = hands;
+
+    loop {
+        if !task.tick() {
+            break;
+        }
+        let p1_card = p1.pop_front().unwrap();
+        let p2_card = p2.pop_front().unwrap();
+        match p1_card > p2_card {
+            true => {
+                p1.push_back(p1_card);
+                p1.push_back(p2_card);
+            }
+            false => {
+                p2.push_back(p2_card);
+                p2.push_back(p1_card);
+            }
+        }
+
+        if p1.is_empty() || p2.is_empty() {
+            break;
+        }
+    }
+
+    get_score(&p1).max(get_score(&p2))
+}
+
+/// Plays a game of recursive combat, returning the winner and their score, ticking `task` for
+/// every round of every game. A game stopped by the task goes to player 1.
+fn pt2(hands: Hands, task: &mut Task) -> (usize, usize) {
+    let (mut p1, mut p2) = hands;
+
+    let mut previous_rounds: HashSet<(VecDeque<usize>, VecDeque<usize>)> = HashSet::new();
+
+    loop {
+        if !task.tick() {
+            return (1, get_score(&p1));
+        }
+
+        //before starting the round, if we've already done this combination in this game, p1 wins
+        if previous_rounds.contains(&(p1.clone(), p2.clone())) {
+            return (1, get_score(&p1.clone()));
+        }
+        previous_rounds.insert((p1.clone(), p2.clone()));
+
+        // draw top cards
+        let p1_card = p1.pop_front().unwrap();
+        let p2_card = p2.pop_front().unwrap();
+
+        let winner = match (
+            p1.len() >= p1_card && p2.len() >= p2_card,
+            p1_card > p2_card,
+        ) {
+            (true, _) => {
+                // RECURSIVE COMBAT
+                pt2(
+                    (
+                        p1.clone().into_iter().take(p1_card).collect(),
+                        p2.clone().into_iter().take(p2_card).collect(),
+                    ),
+                    task,
+                )
+                .0
+            }
+            (false, true) => 1,
+            (false, false) => 2,
+        };
+
+        match winner {
+            1 => {
+                p1.push_back(p1_card);
+                p1.push_back(p2_card);
+            }
+            2 => {
+                p2.push_back(p2_card);
+                p2.push_back(p1_card);
+            }
+            _ => unreachable!(),
+        }
+
+        if p1.is_empty() {
+            return (2, get_score(&p2.clone()));
+        }
+        if p2.is_empty() {
+            return (1, get_score(&p1.clone()));
+        }
+    }
+}
+
+/// The score of a winning hand: each card times its position from the bottom.
+pub fn get_score(hand: &VecDeque<usize>) -> usize {
+    hand.clone()
+        .make_contiguous()
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(idx, val)| (idx + 1) * val)
+        .sum()
+}
+
+fn parse_hands(input: &str) -> Result<Hands, ParseError> {
+    let mut players = sections(input);
+
+    let mut get_cards = |header: &str| {
+        let deck = players.next().unwrap_or(&input[input.len()..]);
+        let cards = deck
+            .strip_prefix(header)
+            .ok_or_else(|| ParseError::new(deck, format!("expected {:?}", header)))?;
+        let cards = cards
+            .lines()
+            .skip(1)
+            .map(value)
+            .collect::<Result<VecDeque<usize>, _>>()?;
+        match cards.is_empty() {
+            true => Err(ParseError::new(deck, "expected at least one card")),
+            false => Ok(cards),
+        }
+    };
+
+    Ok((get_cards("Player 1:")?, get_cards("Player 2:")?))
+}
+
+#[cfg(test)]
+mod day22 {
+    use super::{get_score, Day22, Hands};
+    use aoc_common::config::NoParams;
+    use aoc_common::progress::{monitor, Monitor};
+    use aoc_common::{parses_what_it_prints, run, solve, Part, Solution, SolveError};
+    use proptest::prelude::*;
+    use std::time::Duration;
+
+    /// The cards 1 to `2n` shuffled and dealt evenly between the players.
+    fn hands() -> impl Strategy<Value = Hands> {
+        (1..8_usize)
+            .prop_flat_map(|n| Just((1..=2 * n).collect::<Vec<_>>()).prop_shuffle())
+            .prop_map(|cards| {
+                let (p1, p2) = cards.split_at(cards.len() / 2);
+                (p1.iter().copied().collect(), p2.iter().copied().collect())
+            })
+    }
+
+    /// Player 1 holds the cards `n + 1` to `2n` and player 2 holds 1 to `n`, both shuffled, so
+    /// player 1 wins every round and neither game can go on forever.
+    fn one_sided() -> impl Strategy<Value = Hands> {
+        (1..8_usize).prop_flat_map(|n| {
+            let deck = |cards: Vec<usize>| Just(cards).prop_shuffle().prop_map(Into::into);
+            (deck((n + 1..=2 * n).collect()), deck((1..=n).collect()))
+        })
+    }
+
+    fn print((p1, p2): &Hands) -> String {
+        let deck = |cards: &std::collections::VecDeque<usize>| {
+            cards.iter().map(|c| format!("{}\n", c)).collect::<String>()
+        };
+        format!("Player 1:\n{}\nPlayer 2:\n{}", deck(p1), deck(p2))
+    }
+
+    parses_what_it_prints!(hands() => print, Day22::parse);
+
+    proptest! {
+        #[test]
+        fn the_winner_holds_every_card(hands in one_sided()) {
+            // the score only says who won through its value, so check it against the best and
+            // worst orders the whole deck could end up in
+            let mut cards = hands.0.iter().chain(hands.1.iter()).copied().collect::<Vec<_>>();
+            cards.sort_unstable();
+            let lowest = get_score(&cards.iter().copied().collect());
+            let highest = get_score(&cards.iter().rev().copied().collect());
+
+            for &part in Part::ALL.iter() {
+                let score: usize = solve::<Day22>(part, &print(&hands)).unwrap().parse().unwrap();
+                prop_assert!(lowest <= score && score <= highest);
+            }
+        }
+    }
+
+    #[test]
+    fn should_stop_a_game_that_goes_on_forever() {
+        monitor(Monitor {
+            bar: false,
+            timeout: Some(Duration::from_millis(20)),
+        });
+        // ties go to player 2, whose 1s keep losing straight back to the 2, so the decks repeat
+        let input = "Player 1:\n1\n1\n2\n\nPlayer 2:\n1\n";
+        let reports = run::<Day22>(&[Part::One], input, &NoParams {});
+
+        assert!(matches!(reports[0].answer, Err(SolveError::Timeout(_))));
+    }
+
+    #[test]
+    fn should_reject_an_empty_deck() {
+        let err = Day22::parse("Player 1:\n3\n\nPlayer 2:\n").unwrap_err();
+        assert_eq!(err.reason(), "expected at least one card");
+    }
+}
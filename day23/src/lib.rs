@@ -0,0 +1,347 @@
+use aoc_common::progress::Task;
+use aoc_common::variants::{Variant, Variants};
+use aoc_common::{ParseError, Solution, SolveError};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+pub struct Day23;
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// How many moves the crab makes in part 1.
+    pub part1_moves: usize,
+    /// How many moves the crab makes in part 2.
+    pub part2_moves: usize,
+    /// How many cups there are in part 2, counting the labelled ones.
+    pub part2_cups: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            part1_moves: 100,
+            part2_moves: 10_000_000,
+            part2_cups: 1_000_000,
+        }
+    }
+}
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Params = Params;
+    type Parsed = Vec<usize>;
+    type Answer1 = String;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let input = input.trim();
+        let labels = input
+            .char_indices()
+            .map(|(idx, c)| match c.to_digit(10) {
+                Some(d) if d > 0 => Ok(d as usize),
+                _ => Err(ParseError::new(
+                    &input[idx..idx + c.len_utf8()],
+                    "expected a cup label from 1 to 9",
+                )),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // the cups need to be labelled 1 to n, each exactly once
+        let mut sorted = labels.clone();
+        sorted.sort_unstable();
+        if sorted.is_empty() || sorted.iter().zip(1..).any(|(&l, n)| l != n) {
+            return Err(ParseError::new(
+                input,
+                "expected each label from 1 up to the cup count once",
+            ));
+        }
+        // a move needs a cup to put the three it picks up after, besides the current one
+        if labels.len() < 5 {
+            return Err(ParseError::new(input, "expected at least 5 cups"));
+        }
+
+        Ok(labels)
+    }
+
+    fn part1(labels: &Self::Parsed, params: &Params) -> Result<String, SolveError> {
+        let next = play(labels, params.part1_moves);
+        let mut order = String::new();
+        let mut cup = next[1];
+        while cup != 1 {
+            order.push_str(&cup.to_string());
+            cup = next[cup];
+        }
+        Ok(order)
+    }
+
+    fn part2(labels: &Self::Parsed, params: &Params) -> Result<usize, SolveError> {
+        let labels = with_more_cups(labels, params.part2_cups);
+        let next = play(&labels, params.part2_moves);
+
+        Ok(next[1] * next[next[1]])
+    }
+}
+
+impl Variants for Day23 {
+    fn part1_variants() -> Vec<Variant<Self, String>> {
+        vec![Variant::new(
+            "hash map",
+            |labels: &Self::Parsed, params: &Params| {
+                Ok(part1(&mut parse(labels), labels[0], params.part1_moves))
+            },
+        )]
+    }
+
+    fn part2_variants() -> Vec<Variant<Self, usize>> {
+        vec![Variant::new(
+            "hash map",
+            |labels: &Self::Parsed, params: &Params| {
+                let labels = with_more_cups(labels, params.part2_cups);
+                Ok(part2(&mut parse(&labels), labels[0], params.part2_moves))
+            },
+        )]
+    }
+}
+
+/// The labelled cups followed by the rest of the `cups` in order.
+fn with_more_cups(labels: &[usize], cups: usize) -> Vec<usize> {
+    let mut labels = labels.to_vec();
+    for i in *labels.iter().max().unwrap() + 1..=cups {
+        labels.push(i);
+    }
+    labels
+}
+
+/// Plays `moves` moves, returning the cup clockwise of each cup, indexed by label.
+fn play(labels: &[usize], moves: usize) -> Vec<usize> {
+    let mut task = Task::new::<Day23>("moving cups", moves as u64);
+    let mut next = vec![0; labels.len() + 1];
+    for p in labels.windows(2) {
+        next[p[0]] = p[1];
+    }
+    next[labels[labels.len() - 1]] = labels[0];
+
+    let mut current_cup = labels[0];
+    for _ in 0..moves {
+        if !task.tick() {
+            break;
+        }
+        let first = next[current_cup];
+        let second = next[first];
+        let third = next[second];
+        next[current_cup] = next[third];
+
+        let mut destination = current_cup;
+        loop {
+            destination = match destination {
+                1 => labels.len(),
+                d => d - 1,
+            };
+            if destination != first && destination != second && destination != third {
+                break;
+            }
+        }
+        next[third] = next[destination];
+        next[destination] = first;
+        current_cup = next[current_cup];
+    }
+    next
+}
+
+fn part1(cups: &mut HashMap<usize, usize>, mut current_cup: usize, moves: usize) -> String {
+    for _ in 0..moves {
+        current_cup = step(cups, current_cup);
+    }
+
+    cups_to_vec(cups, 1)
+        .iter()
+        .rev()
+        .skip(1)
+        .rev()
+        .map(|c| c.to_string())
+        .collect()
+}
+
+fn part2(cups: &mut HashMap<usize, usize>, mut current_cup: usize, moves: usize) -> usize {
+    for _ in 0..moves {
+        current_cup = step(cups, current_cup);
+    }
+
+    let first = cups.get(&1).unwrap();
+    let second = cups.get(first).unwrap();
+
+    first * second
+}
+
+fn parse(input: &[usize]) -> HashMap<usize, usize> {
+    let mut res = HashMap::new();
+    for p in input.windows(2) {
+        res.insert(p[0], p[1]);
+    }
+    res.insert(input[input.len() - 1], input[0]); //make it circular
+
+    res
+}
+
+fn cups_to_vec(cups: &HashMap<usize, usize>, start: usize) -> Vec<usize> {
+    let mut current_cup = start;
+    let mut res = vec![];
+    loop {
+        current_cup = *cups.get(&current_cup).unwrap();
+        res.push(current_cup);
+        if current_cup == start {
+            break;
+        }
+    }
+
+    res
+}
+
+fn step(cups: &mut HashMap<usize, usize>, current_cup: usize) -> usize {
+    let taken = take_3(cups, current_cup);
+    let mut destination = current_cup - 1;
+
+    loop {
+        if destination == 0 {
+            destination = cups.len();
+            continue;
+        }
+        match taken.contains(&destination) {
+            false => {
+                break;
+            }
+            true => {
+                destination -= 1;
+            }
+        }
+    }
+    insert(cups, taken, destination);
+    *cups.get(&current_cup).unwrap()
+}
+
+fn take_3(cups: &mut HashMap<usize, usize>, current_cup: usize) -> Vec<usize> {
+    let mut res = vec![];
+    let mut cup = current_cup;
+    for _ in 0..3 {
+        cup = *cups.get(&cup).unwrap();
+        res.push(cup);
+    }
+
+    // join the gap
+    cups.insert(current_cup, *cups.get(&cup).unwrap());
+
+    res
+}
+
+fn insert(cups: &mut HashMap<usize, usize>, to_insert: Vec<usize>, destination: usize) {
+    let prev_ptr = *cups.get(&destination).unwrap();
+    cups.insert(destination, to_insert[0]);
+    cups.insert(to_insert[0], to_insert[1]);
+    cups.insert(to_insert[1], to_insert[2]);
+    cups.insert(to_insert[2], prev_ptr);
+}
+
+#[cfg(test)]
+#[allow(clippy::unnecessary_cast, clippy::useless_vec)]
+mod day23 {
+
+    use super::insert;
+    use super::parse;
+    use super::take_3;
+    use super::{Day23, Params};
+    use crate::HashMap;
+    use aoc_common::{parses_what_it_prints, solve, variants::disagreements, Part, Solution};
+    use proptest::prelude::*;
+    use std::ops::RangeInclusive;
+
+    /// The cups 1 to `n` in a random order.
+    fn labels() -> impl Strategy<Value = Vec<usize>> {
+        labels_up_to(5..=9)
+    }
+
+    /// The cups 1 to `n` in a random order, for `n` in `counts`.
+    fn labels_up_to(counts: RangeInclusive<usize>) -> impl Strategy<Value = Vec<usize>> {
+        counts.prop_flat_map(|n| Just((1..=n).collect::<Vec<_>>()).prop_shuffle())
+    }
+
+    fn print(labels: &[usize]) -> String {
+        labels.iter().map(|l| l.to_string()).collect::<String>() + "\n"
+    }
+
+    parses_what_it_prints!(labels() => |l: &Vec<_>| print(l), Day23::parse);
+
+    proptest! {
+        #[test]
+        fn rejects_fewer_than_five_cups(labels in labels_up_to(1..=4)) {
+            let answer = Day23::parse(&print(&labels)).map_err(|e| e.reason().to_owned());
+            prop_assert_eq!(answer, Err(String::from("expected at least 5 cups")));
+        }
+
+        #[test]
+        fn lists_every_other_cup_once(labels in labels()) {
+            let answer = solve::<Day23>(Part::One, &print(&labels)).unwrap();
+            let mut cups = answer.chars().map(|c| c.to_digit(10).unwrap() as usize).collect::<Vec<_>>();
+            cups.sort_unstable();
+            prop_assert_eq!(cups, (2..=labels.len()).collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn variants_agree(labels in labels(), cups in 9..50_usize, moves in 0..200_usize) {
+            let params = Params {
+                part1_moves: moves,
+                part2_moves: moves,
+                part2_cups: cups,
+            };
+            let found = disagreements::<Day23>(&Part::ALL, &print(&labels), &params);
+            prop_assert_eq!(found, Ok(vec![]));
+        }
+    }
+
+    #[test]
+    fn should_parse() {
+        let res = parse(&vec![1, 2, 3, 4, 5]);
+        let expected = [(1, 2), (2, 3), (3, 4), (4, 5), (5, 1)]
+            .iter()
+            .map(|&(a, b)| (a as usize, b as usize))
+            .collect::<HashMap<usize, usize>>();
+
+        assert_eq!(res, expected);
+    }
+
+    #[test]
+    fn should_take_3() {
+        let cups = parse(&vec![1, 2, 3, 4, 5]);
+
+        assert_eq!(take_3(&mut cups.clone(), 1), vec![2, 3, 4]);
+        assert_eq!(take_3(&mut cups.clone(), 2), vec![3, 4, 5]);
+        assert_eq!(take_3(&mut cups.clone(), 3), vec![4, 5, 1]);
+        assert_eq!(take_3(&mut cups.clone(), 4), vec![5, 1, 2]);
+        assert_eq!(take_3(&mut cups.clone(), 5), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn should_take_3_and_update_cups() {
+        let mut cups = parse(&vec![1, 2, 3, 4, 5]);
+
+        take_3(&mut cups, 1);
+
+        assert_eq!(*cups.get(&1).unwrap(), 5 as usize);
+        assert_eq!(*cups.get(&5).unwrap(), 1 as usize);
+    }
+
+    #[test]
+    fn insert_should_work() {
+        let mut cups = parse(&vec![1, 5]);
+
+        insert(&mut cups, vec![2, 3, 4], 1);
+
+        let expected = [(1, 2), (2, 3), (3, 4), (4, 5), (5, 1)]
+            .iter()
+            .map(|&(a, b)| (a as usize, b as usize))
+            .collect::<HashMap<usize, usize>>();
+
+        assert_eq!(cups, expected);
+    }
+}
@@ -0,0 +1,285 @@
+use aoc_common::automaton::{Automaton, Sparse};
+use aoc_common::geometry::{Hex, HexDirection};
+use aoc_common::grid::{Cell, Grid2D};
+use aoc_common::progress::Task;
+use aoc_common::render::{Frames, Paint, Render};
+use aoc_common::{ParseError, Part, Solution, SolveError};
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::io;
+
+pub struct Day24;
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// How many days the exhibit runs for in part 2.
+    pub days: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params { days: 100 }
+    }
+}
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Params = Params;
+    type Parsed = Vec<Vec<HexDirection>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.lines().map(parse_line).collect()
+    }
+
+    fn part1(paths: &Self::Parsed, _: &Params) -> Result<usize, SolveError> {
+        Ok(handle_paths(paths).len())
+    }
+
+    fn part2(paths: &Self::Parsed, params: &Params) -> Result<usize, SolveError> {
+        let mut floor = lobby(paths);
+        let mut task = Task::new::<Day24>("flipping tiles", params.days as u64);
+        for _ in 0..params.days {
+            if !task.tick() {
+                break;
+            }
+            floor.step();
+        }
+        Ok(floor.live().len())
+    }
+}
+
+impl Render for Day24 {
+    /// Part 1 draws the floor once the tiles are flipped, part 2 adds a frame for every day.
+    fn render(
+        paths: &Self::Parsed,
+        params: &Params,
+        part: Part,
+        frames: &mut Frames,
+    ) -> io::Result<()> {
+        let mut floor = lobby(paths);
+        let mut days = vec![floor.live().clone()];
+        if part == Part::Two {
+            for _ in 0..params.days {
+                floor.step();
+                days.push(floor.live().clone());
+            }
+        }
+
+        let layout = Layout::around(days.iter().flatten());
+        for black in days.iter() {
+            frames.push(&layout.draw(black))?;
+        }
+        Ok(())
+    }
+}
+
+/// The lobby floor as an automaton over the black tiles.
+fn lobby(
+    paths: &[Vec<HexDirection>],
+) -> Sparse<Hex, impl Fn(&Hex) -> Vec<Hex>, impl Fn(bool, usize) -> bool> {
+    Sparse::new(
+        handle_paths(paths),
+        |pos: &Hex| pos.neighbours().to_vec(),
+        |is_black, surrounding_black| match is_black {
+            true => surrounding_black == 1 || surrounding_black == 2,
+            false => surrounding_black == 2,
+        },
+    )
+}
+
+/// A tile as drawn in a frame. Each hex is two cells wide and every other row is shifted by one,
+/// so the `Gap`s fill in around the edges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Black,
+    White,
+    Gap,
+}
+
+impl Cell for Tile {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(Tile::Black),
+            '.' => Some(Tile::White),
+            ' ' => Some(Tile::Gap),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Tile::Black => '#',
+            Tile::White => '.',
+            Tile::Gap => ' ',
+        }
+    }
+}
+
+impl Paint for Tile {
+    fn rgb(&self) -> [u8; 3] {
+        match self {
+            Tile::Black => [30, 30, 30],
+            Tile::White => [235, 235, 225],
+            Tile::Gap => [255, 255, 255],
+        }
+    }
+}
+
+/// Where tiles go in a frame, with the hex at axial `(q, r)` taking up the two cells starting at
+/// column `2q + r`, on row `r`.
+struct Layout {
+    min_column: i32,
+    min_row: i32,
+    width: usize,
+    height: usize,
+}
+
+impl Layout {
+    fn around<'a>(tiles: impl Iterator<Item = &'a Hex> + Clone) -> Layout {
+        let column = |hex: &Hex| {
+            let (q, r) = hex.axial();
+            2 * q + r
+        };
+        let row = |hex: &Hex| hex.axial().1;
+        let (min_column, max_column) = (
+            tiles.clone().map(column).min().unwrap_or(0),
+            tiles.clone().map(column).max().unwrap_or(0),
+        );
+        let (min_row, max_row) = (
+            tiles.clone().map(row).min().unwrap_or(0),
+            tiles.map(row).max().unwrap_or(0),
+        );
+        Layout {
+            min_column,
+            min_row,
+            width: (max_column - min_column + 2) as usize,
+            height: (max_row - min_row + 1) as usize,
+        }
+    }
+
+    fn draw(&self, black: &Map) -> Grid2D<Tile> {
+        Grid2D::from_fn(self.width, self.height, |(c, row)| {
+            let r = self.min_row + row as i32;
+            let column = self.min_column + c as i32;
+            // the hex covering this cell starts here or one to the left, whichever has the
+            // same parity as the row
+            let start = column - (column - r).rem_euclid(2);
+            let pos = Hex::from_axial((start - r) / 2, r);
+            if start < self.min_column || start + 1 >= self.min_column + self.width as i32 {
+                Tile::Gap
+            } else if black.contains(&pos) {
+                Tile::Black
+            } else {
+                Tile::White
+            }
+        })
+    }
+}
+
+/// The positions of the tiles that are black side up.
+type Map = HashSet<Hex>;
+
+/// Flips the tile at the end of each path, returning the tiles left black side up.
+pub fn handle_paths(paths: &[Vec<HexDirection>]) -> Map {
+    let mut map: Map = Map::new();
+
+    for path in paths.iter() {
+        //step all the way along, flip the tile
+        let pos = path.iter().fold(Hex::ORIGIN, |pos, &d| pos.step(d));
+        if !map.remove(&pos) {
+            map.insert(pos);
+        }
+    }
+
+    map
+}
+
+fn parse_line(input: &str) -> Result<Vec<HexDirection>, ParseError> {
+    let mut result = Vec::new();
+    let mut chars = input.char_indices();
+
+    while let Some((idx, c)) = chars.next() {
+        let direction = match c {
+            'e' => HexDirection::East,
+            'w' => HexDirection::West,
+            'n' | 's' => match (c, chars.next()) {
+                ('n', Some((_, 'e'))) => HexDirection::NorthEast,
+                ('n', Some((_, 'w'))) => HexDirection::NorthWest,
+                ('s', Some((_, 'e'))) => HexDirection::SouthEast,
+                ('s', Some((_, 'w'))) => HexDirection::SouthWest,
+                (_, next) => {
+                    let end = next.map_or(input.len(), |(i, n)| i + n.len_utf8());
+                    return Err(ParseError::new(
+                        &input[idx..end],
+                        "expected `ne`, `nw`, `se` or `sw`",
+                    ));
+                }
+            },
+            _ => {
+                return Err(ParseError::new(
+                    &input[idx..idx + c.len_utf8()],
+                    "expected `e`, `se`, `sw`, `w`, `nw` or `ne`",
+                ))
+            }
+        };
+        result.push(direction);
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod day24 {
+    use super::{parse_line, Day24};
+    use aoc_common::geometry::{Hex, HexDirection};
+    use aoc_common::{parses_what_it_prints, solve, Part};
+    use proptest::{collection::vec, prelude::*};
+
+    const DIRECTIONS: [(HexDirection, &str); 6] = [
+        (HexDirection::East, "e"),
+        (HexDirection::SouthEast, "se"),
+        (HexDirection::SouthWest, "sw"),
+        (HexDirection::West, "w"),
+        (HexDirection::NorthWest, "nw"),
+        (HexDirection::NorthEast, "ne"),
+    ];
+
+    fn path() -> impl Strategy<Value = Vec<HexDirection>> {
+        vec(
+            prop::sample::select(DIRECTIONS.to_vec()).prop_map(|(d, _)| d),
+            1..20,
+        )
+    }
+
+    fn print(paths: &[Vec<HexDirection>]) -> String {
+        let text = |d: &HexDirection| DIRECTIONS.iter().find(|(o, _)| o == d).unwrap().1;
+        paths
+            .iter()
+            .map(|p| p.iter().map(text).collect::<String>() + "\n")
+            .collect()
+    }
+
+    parses_what_it_prints!(path() => |p: &Vec<_>| print(std::slice::from_ref(p)), |line: &str| {
+        parse_line(line.trim_end())
+    });
+
+    proptest! {
+        #[test]
+        fn walking_back_returns_to_the_start(path in path()) {
+            let there = path.iter().fold(Hex::ORIGIN, |pos, &d| pos.step(d));
+            let back = path.iter().rev().fold(there, |pos, &d| pos.step(d.opposite()));
+            prop_assert_eq!(back, Hex::ORIGIN);
+        }
+
+        #[test]
+        fn flipping_a_tile_twice_leaves_it_white(paths in vec(path(), 1..20)) {
+            let mut twice = paths.clone();
+            twice.extend(paths.iter().map(|p| p.iter().rev().copied().collect()));
+            prop_assert_eq!(solve::<Day24>(Part::One, &print(&twice)), Ok(String::from("0")));
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use aoc_common::number_theory::{discrete_log, mod_pow};
+use aoc_common::parse::numbers;
+use aoc_common::{ParseError, Solution, SolveError, Unsolvable};
+use serde::Deserialize;
+
+pub struct Day25;
+
+/// The subject number the public keys are transformed from.
+pub const SUBJECT: u64 = 7;
+
+/// Every transformation is taken modulo this prime.
+pub const MODULUS: u64 = 20201227;
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// The subject number the public keys are transformed from, [`SUBJECT`] in the puzzle.
+    pub subject: u64,
+    /// The prime every transformation is taken modulo, [`MODULUS`] in the puzzle.
+    pub modulus: u64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            subject: SUBJECT,
+            modulus: MODULUS,
+        }
+    }
+}
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    type Params = Params;
+    type Parsed = (u64, u64);
+    type Answer1 = u64;
+    type Answer2 = &'static str;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let keys: Vec<u64> = numbers(input)?;
+        if keys.len() != 2 {
+            return Err(ParseError::new(
+                input,
+                "expected the card and door public keys",
+            ));
+        }
+        // how large a key can be depends on the modulus, which `solve` checks them against
+        if let Some(idx) = keys.iter().position(|&k| k == 0) {
+            let key = input.lines().nth(idx).unwrap().trim();
+            return Err(ParseError::new(key, "public keys can't be 0"));
+        }
+
+        Ok((keys[0], keys[1]))
+    }
+
+    fn part1(&(card, door): &Self::Parsed, params: &Params) -> Result<u64, SolveError> {
+        Ok(solve(card, door, params)?)
+    }
+
+    fn part2(_: &Self::Parsed, _: &Params) -> Result<&'static str, SolveError> {
+        // there is no puzzle for the last star
+        Ok("Merry Christmas!")
+    }
+
+    fn check_params(params: &Params) -> Result<(), String> {
+        if params.modulus < 2 {
+            return Err(String::from("the modulus needs to be at least 2"));
+        }
+        if params.subject == 0 || params.subject >= params.modulus {
+            return Err(format!(
+                "the subject number needs to be between 1 and {}",
+                params.modulus - 1
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// The encryption key for a card and door public key pair.
+pub fn solve(pk1: u64, pk2: u64, params: &Params) -> Result<u64, Unsolvable> {
+    if pk1 >= params.modulus || pk2 >= params.modulus {
+        let reason = format!("public keys must be between 1 and {}", params.modulus - 1);
+        return Err(Unsolvable::new::<Day25>(reason));
+    }
+    // the puzzle's subject number is a primitive root of the modulus, so every key has a loop
+    // size, but other subjects needn't be
+    let loop_size = discrete_log(params.subject, pk2, params.modulus).ok_or_else(|| {
+        let reason = format!("no loop size turns {} into {}", params.subject, pk2);
+        Unsolvable::new::<Day25>(reason)
+    })?;
+    Ok(get_ek(pk1, loop_size, params.modulus))
+}
+
+/// Transforms `pk` with a loop size of `loops`.
+pub fn get_ek(pk: u64, loops: u64, modulus: u64) -> u64 {
+    mod_pow(pk, loops, modulus)
+}
+
+#[cfg(test)]
+mod day25 {
+    use super::{get_ek, Day25, Params, MODULUS, SUBJECT};
+    use aoc_common::{config::Config, parses_what_it_prints, solve, solve_with, Part, Solution};
+    use proptest::prelude::*;
+
+    parses_what_it_prints!((1..MODULUS, 1..MODULUS) => |(card, door): &(u64, u64)| {
+        format!("{}\n{}\n", card, door)
+    }, Day25::parse);
+
+    proptest! {
+        #[test]
+        fn finds_the_shared_encryption_key(card_loops in 1..2000_u64, door_loops in 1..2000_u64) {
+            let input = format!("{}\n{}\n", get_ek(SUBJECT, card_loops, MODULUS), get_ek(SUBJECT, door_loops, MODULUS));
+            let expected = get_ek(SUBJECT, card_loops * door_loops, MODULUS);
+            prop_assert_eq!(solve::<Day25>(Part::One, &input), Ok(expected.to_string()));
+        }
+    }
+    #[test]
+    fn should_reject_a_subject_outside_the_modulus() {
+        let config = Config::parse("[day25]\nsubject = 7\nmodulus = 7\n").unwrap();
+
+        assert_eq!(
+            config.params::<Day25>().unwrap_err().to_string(),
+            "[day25]: the subject number needs to be between 1 and 6"
+        );
+    }
+
+    #[test]
+    fn should_reject_keys_outside_the_modulus() {
+        let params = Params {
+            subject: 2,
+            modulus: 11,
+        };
+        let answer = solve_with::<Day25>(Part::One, "12\n3\n", &params);
+        assert_eq!(
+            answer.map_err(|e| e.to_string()),
+            Err(String::from("day 25: public keys must be between 1 and 10"))
+        );
+    }
+
+    #[test]
+    fn should_report_keys_no_loop_size_gives() {
+        // 4 only ever gives 4, 5, 9, 3 and 1 modulo 11
+        let params = Params {
+            subject: 4,
+            modulus: 11,
+        };
+        let answer = solve_with::<Day25>(Part::One, "5\n2\n", &params);
+        assert_eq!(
+            answer.map_err(|e| e.to_string()),
+            Err(String::from("day 25: no loop size turns 4 into 2"))
+        );
+    }
+}
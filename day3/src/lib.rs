@@ -0,0 +1,133 @@
+use aoc_common::arith::Checked;
+use aoc_common::grid::{Cell, Grid2D};
+use aoc_common::{ParseError, Solution, SolveError};
+use serde::Deserialize;
+use std::iter;
+
+pub struct Day3;
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// How far right and down to go each step in part 1.
+    pub part1_slope: (usize, usize),
+    /// The slopes whose trees are multiplied together in part 2.
+    pub part2_slopes: Vec<(usize, usize)>,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            part1_slope: (3, 1),
+            part2_slopes: vec![(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)],
+        }
+    }
+}
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Params = Params;
+    type Parsed = Grid2D<Square>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(landscape: &Self::Parsed, params: &Params) -> Result<usize, SolveError> {
+        let (right, down) = params.part1_slope;
+        Ok(trees_on_slope(landscape, right, down))
+    }
+
+    fn part2(landscape: &Self::Parsed, params: &Params) -> Result<usize, SolveError> {
+        let trees = params
+            .part2_slopes
+            .iter()
+            .map(|slope| trees_on_slope(landscape, slope.0, slope.1));
+        Ok(Checked::new::<Day3>("the product of trees").product(trees))
+    }
+
+    fn check_params(params: &Params) -> Result<(), String> {
+        let mut slopes = iter::once(&params.part1_slope).chain(&params.part2_slopes);
+        if slopes.any(|&(_, down)| down == 0) {
+            return Err(String::from(
+                "every slope needs to go down at least 1 each step",
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Square {
+    Open,
+    Tree,
+}
+
+impl Cell for Square {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Square::Open),
+            '#' => Some(Square::Tree),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Square::Open => '.',
+            Square::Tree => '#',
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Grid2D<Square>, ParseError> {
+    Grid2D::parse(input)
+}
+
+/// Counts the trees hit going `right` and `down` each step from the top left, wrapping horizontally.
+pub fn trees_on_slope(landscape: &Grid2D<Square>, right: usize, down: usize) -> usize {
+    // the pattern repeats to the right, so wrap around instead of running off the edge
+    (0..landscape.height())
+        .step_by(down)
+        .enumerate()
+        .map(|(step, y)| landscape.wrapping_offset((0, y), ((step * right) as i64, 0)))
+        .filter(|&pos| landscape[pos] == Square::Tree)
+        .count()
+}
+
+#[cfg(test)]
+mod day3 {
+    use super::{parse_input, Day3};
+    use aoc_common::config::Config;
+    use proptest::{collection::vec, prelude::*};
+
+    fn landscape() -> impl Strategy<Value = String> {
+        (1..12_usize, 1..12_usize).prop_flat_map(|(width, height)| {
+            vec(vec(prop_oneof![Just('.'), Just('#')], width), height).prop_map(|rows| {
+                rows.iter()
+                    .map(|row| row.iter().collect::<String>() + "\n")
+                    .collect()
+            })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn prints_what_it_parses(input in landscape()) {
+            prop_assert_eq!(parse_input(&input).unwrap().to_string(), input.trim_end());
+        }
+    }
+
+    #[test]
+    fn should_reject_a_slope_that_never_goes_down() {
+        let config = Config::parse("[day3]\npart2_slopes = [[1, 1], [2, 0]]\n").unwrap();
+
+        assert_eq!(
+            config.params::<Day3>().unwrap_err().to_string(),
+            "[day3]: every slope needs to go down at least 1 each step"
+        );
+    }
+}
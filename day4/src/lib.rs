@@ -0,0 +1,305 @@
+use aoc_common::config::NoParams;
+use aoc_common::parse::sections;
+use aoc_common::{ParseError, Solution, SolveError};
+use regex::Regex;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Params = NoParams;
+    type Parsed = Vec<Passport>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        sections(input)
+            .map(|p| p.lines().collect())
+            .map(Passport::new)
+            .collect()
+    }
+
+    fn part1(passports: &Self::Parsed, _: &Self::Params) -> Result<usize, SolveError> {
+        Ok(passports.iter().filter(|p| p.has_required_fields()).count())
+    }
+
+    fn part2(passports: &Self::Parsed, _: &Self::Params) -> Result<usize, SolveError> {
+        Ok(passports
+            .iter()
+            .filter(|p| p.has_required_fields())
+            .filter(|p| p.is_valid())
+            .count())
+    }
+}
+
+const FIELDS: [&str; 8] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid", "cid"];
+
+#[derive(Clone, Debug)]
+pub struct Passport {
+    birth_year: Option<String>,
+    issue_year: Option<String>,
+    expiration_year: Option<String>,
+    height: Option<String>,
+    hair_color: Option<String>,
+    eye_color: Option<String>,
+    passport_id: Option<String>,
+    // cid is parsed but deliberately ignored by both policies
+    #[allow(dead_code)]
+    country_id: Option<String>,
+}
+
+impl Passport {
+    fn new(lines: Vec<&str>) -> Result<Passport, ParseError> {
+        let props = lines
+            .iter()
+            .flat_map(|l| l.split_ascii_whitespace().collect::<Vec<&str>>())
+            .collect::<Vec<&str>>();
+
+        for prop in props.iter() {
+            match prop.split_once(':') {
+                Some((key, _)) if FIELDS.contains(&key) => {}
+                Some((key, _)) => return Err(ParseError::new(key, "unknown passport field")),
+                None => return Err(ParseError::new(prop, "expected `key:value`")),
+            }
+        }
+
+        Ok(Passport {
+            birth_year: get_value("byr", &props),
+            issue_year: get_value("iyr", &props),
+            expiration_year: get_value("eyr", &props),
+            height: get_value("hgt", &props),
+            hair_color: get_value("hcl", &props),
+            eye_color: get_value("ecl", &props),
+            passport_id: get_value("pid", &props),
+            country_id: get_value("cid", &props),
+        })
+    }
+
+    /// Whether every field other than `cid` is present.
+    pub fn has_required_fields(&self) -> bool {
+        [
+            &self.birth_year,
+            &self.issue_year,
+            &self.expiration_year,
+            &self.height,
+            &self.hair_color,
+            &self.eye_color,
+            &self.passport_id,
+        ]
+        .iter()
+        .all(|f| f.is_some())
+    }
+
+    /// Whether every required field is present and holds a valid value.
+    pub fn is_valid(&self) -> bool {
+        fn field(f: &Option<String>) -> &str {
+            f.as_deref().unwrap()
+        }
+
+        Passport::is_valid_year(field(&self.birth_year), 1920, 2002)
+            && Passport::is_valid_year(field(&self.issue_year), 2010, 2020)
+            && Passport::is_valid_year(field(&self.expiration_year), 2020, 2030)
+            && Passport::is_valid_height(field(&self.height))
+            && Passport::is_valid_hair_color(field(&self.hair_color))
+            && Passport::is_valid_eye_color(field(&self.eye_color))
+            && Passport::is_valid_pid(field(&self.passport_id))
+    }
+
+    fn is_valid_hair_color(input: &str) -> bool {
+        let re = Regex::new("^#[a-f0-9]{6}$").unwrap();
+        re.is_match(input)
+    }
+
+    fn is_valid_height(input: &str) -> bool {
+        if !input.ends_with("cm") && !input.ends_with("in") {
+            return false;
+        }
+
+        let v = match input.replace("cm", "").replace("in", "").parse::<usize>() {
+            Ok(v) => v,
+            Err(_) => return false,
+        };
+
+        match (input.ends_with("cm"), input.ends_with("in")) {
+            (true, false) => is_inbetween_inclusive(v, 150, 193),
+            (false, true) => is_inbetween_inclusive(v, 59, 76),
+            _ => false,
+        }
+    }
+
+    fn is_valid_eye_color(input: &str) -> bool {
+        ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"].contains(&input)
+    }
+
+    fn is_valid_pid(input: &str) -> bool {
+        Regex::new("^[\\d]{9}$").unwrap().is_match(input)
+    }
+
+    fn is_valid_year(input: &str, min: usize, max: usize) -> bool {
+        match input.parse::<usize>() {
+            Ok(val) => is_inbetween_inclusive(val, min, max),
+            Err(_) => false,
+        }
+    }
+}
+
+fn get_value(key: &str, props: &[&str]) -> Option<String> {
+    match props.iter().find(|&&p| p.starts_with(key)) {
+        None => None,
+        Some(&a) => a.split(':').nth(1).map(str::to_owned),
+    }
+}
+
+fn is_inbetween_inclusive(num: usize, min: usize, max: usize) -> bool {
+    num >= min && num <= max
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod day4 {
+    use super::{Day4, Passport, FIELDS};
+    use aoc_common::Solution;
+    use proptest::{collection::vec, prelude::*};
+
+    /// A passport as the value of each of [`FIELDS`], if it has one.
+    type Fields = Vec<Option<String>>;
+
+    fn value() -> impl Strategy<Value = String> {
+        prop_oneof![
+            "[0-9]{2,4}(cm|in)?",
+            "#[0-9a-f]{6}",
+            "[a-z]{3}",
+            "[0-9a-z#]{1,10}",
+        ]
+    }
+
+    fn passport() -> impl Strategy<Value = Fields> {
+        vec(prop::option::of(value()), FIELDS.len()).prop_filter("a passport needs a field", |f| {
+            f.iter().any(Option::is_some)
+        })
+    }
+
+    /// Prints the fields in a random order, each separated by a space or a newline.
+    fn print(passport: &Fields) -> impl Strategy<Value = String> {
+        let props = FIELDS
+            .iter()
+            .zip(passport)
+            .filter_map(|(key, value)| Some(format!("{}:{}", key, value.as_ref()?)))
+            .collect::<Vec<_>>();
+        let count = props.len();
+
+        (
+            Just(props).prop_shuffle(),
+            vec(prop_oneof![Just(' '), Just('\n')], count),
+        )
+            .prop_map(|(props, separators)| {
+                let mut text = String::new();
+                for (prop, separator) in props.iter().zip(separators) {
+                    if !text.is_empty() {
+                        text.push(separator);
+                    }
+                    text.push_str(prop);
+                }
+                text
+            })
+    }
+
+    fn batch() -> impl Strategy<Value = (Vec<Fields>, String)> {
+        vec(passport(), 1..8).prop_flat_map(|passports| {
+            let printed = passports.iter().map(print).collect::<Vec<_>>();
+            (Just(passports), printed.prop_map(|p| p.join("\n\n") + "\n"))
+        })
+    }
+
+    fn fields(passport: &Passport) -> Fields {
+        vec![
+            passport.birth_year.clone(),
+            passport.issue_year.clone(),
+            passport.expiration_year.clone(),
+            passport.height.clone(),
+            passport.hair_color.clone(),
+            passport.eye_color.clone(),
+            passport.passport_id.clone(),
+            passport.country_id.clone(),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn parses_what_it_prints((passports, input) in batch()) {
+            let parsed = Day4::parse(&input).unwrap();
+            prop_assert_eq!(parsed.iter().map(fields).collect::<Vec<_>>(), passports);
+        }
+    }
+
+    #[test]
+    fn is_valid_year() {
+        assert_eq!(true, Passport::is_valid_year("1999", 1920, 2002));
+        assert_eq!(true, Passport::is_valid_year("1920", 1920, 2002));
+        assert_eq!(true, Passport::is_valid_year("2002", 1920, 2002));
+
+        assert_eq!(false, Passport::is_valid_year("1919", 1920, 2002));
+        assert_eq!(false, Passport::is_valid_year("2003", 1920, 2002));
+        assert_eq!(false, Passport::is_valid_year("199", 1920, 2002));
+        assert_eq!(false, Passport::is_valid_year("abcs", 1920, 2002));
+        assert_eq!(false, Passport::is_valid_year("22222", 1920, 2002));
+    }
+
+    #[test]
+    fn is_valid_height() {
+        assert_eq!(true, Passport::is_valid_height("150cm"));
+        assert_eq!(true, Passport::is_valid_height("160cm"));
+        assert_eq!(true, Passport::is_valid_height("193cm"));
+
+        assert_eq!(true, Passport::is_valid_height("59in"));
+        assert_eq!(true, Passport::is_valid_height("76in"));
+        assert_eq!(true, Passport::is_valid_height("61in"));
+
+        assert_eq!(false, Passport::is_valid_height("149cm"));
+        assert_eq!(false, Passport::is_valid_height("1600cm"));
+        assert_eq!(false, Passport::is_valid_height("194cm"));
+
+        assert_eq!(false, Passport::is_valid_height("58in"));
+        assert_eq!(false, Passport::is_valid_height("7600in"));
+        assert_eq!(false, Passport::is_valid_height("77in"));
+
+        assert_eq!(false, Passport::is_valid_height("190"));
+        assert_eq!(false, Passport::is_valid_height("190in"));
+        assert!(!Passport::is_valid_height("tallcm"));
+    }
+
+    #[test]
+    fn is_valid_hair_color() {
+        assert_eq!(true, Passport::is_valid_hair_color("#111111"));
+        assert_eq!(true, Passport::is_valid_hair_color("#aaaaaa"));
+        assert_eq!(true, Passport::is_valid_hair_color("#a11aaa"));
+
+        assert_eq!(false, Passport::is_valid_hair_color("#11111"));
+        assert_eq!(false, Passport::is_valid_hair_color("#gggggg"));
+        assert_eq!(false, Passport::is_valid_hair_color("#a11aaaaa"));
+
+        assert_eq!(false, Passport::is_valid_hair_color("#123abz"));
+        assert_eq!(false, Passport::is_valid_hair_color("a97842"));
+    }
+    #[test]
+    fn is_valid_eye_color() {
+        assert_eq!(true, Passport::is_valid_eye_color("amb"));
+        assert_eq!(true, Passport::is_valid_eye_color("blu"));
+        assert_eq!(true, Passport::is_valid_eye_color("brn"));
+
+        assert_eq!(false, Passport::is_valid_eye_color("amber"));
+        assert_eq!(false, Passport::is_valid_eye_color("#1234"));
+        assert_eq!(false, Passport::is_valid_eye_color("abcd"));
+
+        assert_eq!(false, Passport::is_valid_eye_color("wat"));
+    }
+
+    #[test]
+    fn is_valid_pid() {
+        assert_eq!(true, Passport::is_valid_pid("000000001"));
+        assert_eq!(true, Passport::is_valid_pid("111111111"));
+
+        assert_eq!(false, Passport::is_valid_pid("0123456789"));
+    }
+}
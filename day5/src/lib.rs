@@ -0,0 +1,138 @@
+use aoc_common::config::NoParams;
+use aoc_common::{ParseError, Solution, SolveError, Unsolvable};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Params = NoParams;
+    type Parsed = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input
+            .lines()
+            .map(get_seat_details)
+            .map(|seat| seat.map(|(r, c)| r * 8 + c))
+            .collect()
+    }
+
+    fn part1(passes: &Self::Parsed, _: &Self::Params) -> Result<usize, SolveError> {
+        (passes.iter().max().copied())
+            .ok_or_else(|| Unsolvable::new::<Day5>("there are no boarding passes").into())
+    }
+
+    fn part2(passes: &Self::Parsed, _: &Self::Params) -> Result<usize, SolveError> {
+        let missing = match (passes.iter().min(), passes.iter().max()) {
+            (Some(&min_seat_id), Some(&max_seat_id)) => {
+                (min_seat_id..max_seat_id).find(|sid| !passes.contains(sid))
+            }
+            _ => None,
+        };
+        missing
+            .ok_or_else(|| Unsolvable::new::<Day5>("no seat is missing between the passes").into())
+    }
+}
+
+/// Decodes a boarding pass like `FBFBBFFRLR` into its row and column.
+pub fn get_seat_details(input: &str) -> Result<(usize, usize), ParseError> {
+    for (idx, c) in input.char_indices() {
+        let halves = if idx < 7 { "FB" } else { "LR" };
+        if !halves.contains(c) {
+            let reason = format!("expected one of {:?}", halves);
+            return Err(ParseError::new(&input[idx..idx + c.len_utf8()], reason));
+        }
+    }
+    if input.len() != 10 {
+        return Err(ParseError::new(input, "expected 10 characters"));
+    }
+
+    Ok((wittle_down(&input[..7]), wittle_down(&input[7..])))
+}
+
+fn wittle_down(input: &str) -> usize {
+    // we know the size based on the input, its 2^(len(input)-1)
+    let mut current_slice: &[usize] = &(0..(2 << (input.len() - 1))).collect::<Vec<usize>>();
+    // avoid recursion, its not needed
+    for c in input.chars() {
+        current_slice = current_slice
+            .chunks(current_slice.len() / 2)
+            .nth(match c {
+                'F' | 'L' => 0,
+                'B' | 'R' => 1,
+                _ => unreachable!(),
+            })
+            .unwrap();
+    }
+    current_slice[0]
+}
+
+#[cfg(test)]
+mod day5 {
+    use super::{get_seat_details, Day5};
+    use aoc_common::{solve, Part};
+    use proptest::prelude::*;
+
+    fn encode(row: usize, column: usize) -> String {
+        let row = (0..7)
+            .rev()
+            .map(|bit| if row >> bit & 1 == 1 { 'B' } else { 'F' });
+        let column = (0..3)
+            .rev()
+            .map(|bit| if column >> bit & 1 == 1 { 'R' } else { 'L' });
+        row.chain(column).collect()
+    }
+
+    /// A full flight's passes, shuffled, along with the one seat id missing from them.
+    fn flight() -> impl Strategy<Value = (String, usize)> {
+        (1..970_usize, 2..50_usize)
+            .prop_flat_map(|(first, len)| (Just(first), Just(len), 1..len))
+            .prop_flat_map(|(first, len, gap)| {
+                let passes = (first..=first + len)
+                    .filter(|&id| id != first + gap)
+                    .map(|id| encode(id / 8, id % 8) + "\n")
+                    .collect::<Vec<_>>();
+                (Just(passes).prop_shuffle(), Just(first + gap))
+            })
+            .prop_map(|(passes, missing)| (passes.concat(), missing))
+    }
+
+    proptest! {
+        #[test]
+        fn decodes_what_it_encodes(row in 0..128_usize, column in 0..8_usize) {
+            prop_assert_eq!(get_seat_details(&encode(row, column)), Ok((row, column)));
+        }
+
+        #[test]
+        fn only_decodes_seven_rows_then_three_columns(pass in "[FBLR]{0,12}") {
+            let well_formed = pass.len() == 10
+                && pass[..7].chars().all(|c| "FB".contains(c))
+                && pass[7..].chars().all(|c| "LR".contains(c));
+            prop_assert_eq!(get_seat_details(&pass).is_ok(), well_formed);
+        }
+
+        #[test]
+        fn finds_the_missing_seat((input, missing) in flight()) {
+            prop_assert_eq!(solve::<Day5>(Part::Two, &input), Ok(missing.to_string()));
+        }
+    }
+
+    #[test]
+    fn should_report_a_flight_without_a_gap() {
+        let input = format!("{}\n{}\n", encode(10, 0), encode(10, 1));
+        let answer = solve::<Day5>(Part::Two, &input).map_err(|e| e.to_string());
+        assert_eq!(
+            answer,
+            Err(String::from("day 5: no seat is missing between the passes"))
+        );
+    }
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(Ok((70, 7)), get_seat_details("BFFFBBFRRR"));
+        assert_eq!(Ok((14, 7)), get_seat_details("FFFBBBFRRR"));
+        assert_eq!(Ok((102, 4)), get_seat_details("BBFFBBFRLL"));
+    }
+}
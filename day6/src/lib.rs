@@ -0,0 +1,105 @@
+use aoc_common::config::NoParams;
+use aoc_common::parse::sections;
+use aoc_common::{ParseError, Solution, SolveError};
+use std::collections::HashSet;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Params = NoParams;
+    type Parsed = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let invalid = input
+            .char_indices()
+            .find(|&(_, c)| !c.is_ascii_lowercase() && c != '\n');
+        if let Some((idx, c)) = invalid {
+            return Err(ParseError::new(
+                &input[idx..idx + c.len_utf8()],
+                "expected a question from `a` to `z`",
+            ));
+        }
+
+        Ok(sections(input).map(str::to_owned).collect())
+    }
+
+    fn part1(groups: &Self::Parsed, _: &Self::Params) -> Result<usize, SolveError> {
+        Ok(groups.iter().fold(0, |acc, g| acc + count_unique_chars(g)))
+    }
+
+    fn part2(groups: &Self::Parsed, _: &Self::Params) -> Result<usize, SolveError> {
+        Ok(groups
+            .iter()
+            .map(|g| g.lines().collect::<Vec<&str>>())
+            .fold(0, |acc, g| acc + count_all_answered(g)))
+    }
+}
+
+fn count_unique_chars(input: &str) -> usize {
+    input
+        .chars()
+        .filter(|c| c.is_alphabetic())
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+fn count_all_answered(group: Vec<&str>) -> usize {
+    let alphabet = (b'a'..=b'z').map(char::from);
+    alphabet.fold(0, |acc, c| acc + all_answered(&group, &c) as usize)
+}
+
+fn all_answered(group: &[&str], c: &char) -> bool {
+    group
+        .iter()
+        .map(|&g| g.chars().collect::<Vec<char>>())
+        .all(|g| g.contains(c))
+}
+
+#[cfg(test)]
+mod day6 {
+    use super::Day6;
+    use aoc_common::{solve, Part};
+    use proptest::{collection::vec, prelude::*};
+    use std::collections::BTreeSet;
+
+    /// Each group's people, each person being the questions they answered yes to.
+    type Groups = Vec<Vec<BTreeSet<char>>>;
+
+    fn groups() -> impl Strategy<Value = Groups> {
+        let person = prop::collection::btree_set(prop::char::range('a', 'z'), 1..8);
+        vec(vec(person, 1..5), 1..10)
+    }
+
+    fn print(groups: &Groups) -> String {
+        let groups = groups
+            .iter()
+            .map(|group| {
+                let people = group.iter().map(|p| p.iter().collect::<String>());
+                people.collect::<Vec<_>>().join("\n")
+            })
+            .collect::<Vec<_>>();
+        groups.join("\n\n") + "\n"
+    }
+
+    proptest! {
+        #[test]
+        fn counts_questions_anyone_and_everyone_answered(groups in groups()) {
+            let anyone = groups
+                .iter()
+                .map(|g| g.iter().flatten().collect::<BTreeSet<_>>().len())
+                .sum::<usize>();
+            let everyone = groups
+                .iter()
+                .map(|g| ('a'..='z').filter(|c| g.iter().all(|p| p.contains(c))).count())
+                .sum::<usize>();
+
+            let input = print(&groups);
+            prop_assert_eq!(solve::<Day6>(Part::One, &input), Ok(anyone.to_string()));
+            prop_assert_eq!(solve::<Day6>(Part::Two, &input), Ok(everyone.to_string()));
+        }
+    }
+}
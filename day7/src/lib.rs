@@ -0,0 +1,275 @@
+use aoc_common::explain::Explain;
+use aoc_common::graph::{Graph, NodeId};
+use aoc_common::parse::value;
+use aoc_common::{ParseError, Solution, SolveError};
+use regex::Regex;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+
+pub struct Day7;
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// The color of the bag you're carrying.
+    pub bag: String,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            bag: String::from("shiny gold"),
+        }
+    }
+}
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Params = Params;
+    type Parsed = Bags;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(bags: &Self::Parsed, params: &Params) -> Result<usize, SolveError> {
+        Ok(part1(bags, &params.bag))
+    }
+
+    fn part2(bags: &Self::Parsed, params: &Params) -> Result<usize, SolveError> {
+        Ok(part2(bags, &params.bag))
+    }
+}
+
+pub type Bag = (usize, String);
+pub type Rules = HashMap<String, Vec<Bag>>;
+
+/// A graph with an edge from each bag to the bags it directly contains, weighted by how many
+/// it holds.
+pub struct Bags {
+    pub graph: Graph<String, usize>,
+    /// The node of each color. Colors are added in alphabetical order.
+    pub ids: HashMap<String, NodeId>,
+    /// Every bag ahead of the bags it contains.
+    pub order: Vec<NodeId>,
+}
+
+impl Bags {
+    /// The bags the rules describe, or the colors of bags that end up inside themselves, each
+    /// directly containing the next.
+    pub fn new(rules: &Rules) -> Result<Bags, Vec<String>> {
+        let mut colors = rules.keys().collect::<Vec<_>>();
+        colors.sort();
+
+        let mut graph = Graph::new();
+        let ids = colors
+            .into_iter()
+            .map(|color| (color.clone(), graph.add_node(color.clone())))
+            .collect::<HashMap<_, _>>();
+
+        for (color, contents) in rules.iter() {
+            for (amount, inner) in contents.iter() {
+                graph.add_edge(ids[color], ids[inner], *amount);
+            }
+        }
+
+        match graph.topological_order() {
+            Ok(order) => Ok(Bags { graph, ids, order }),
+            Err(cycle) => Err(cycle.0.iter().map(|&id| graph.node(id).clone()).collect()),
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Bags, ParseError> {
+    // a bag that ends up inside itself would need infinitely many bags
+    Bags::new(&parse_rules(input)?).map_err(|colors| {
+        let rule = input
+            .lines()
+            .find(|l| {
+                l.strip_prefix(colors[0].as_str())
+                    .is_some_and(|r| r.starts_with(" bags"))
+            })
+            .unwrap();
+        ParseError::new(
+            &rule[..colors[0].len()],
+            format!(
+                "bags can't contain themselves: {} -> {}",
+                colors.join(" -> "),
+                colors[0]
+            ),
+        )
+    })
+}
+
+fn parse_rules(input: &str) -> Result<Rules, ParseError> {
+    let re1 = Regex::new(r"^(.*) bags contain (.*)$").unwrap();
+    let re2 = Regex::new(r"(\d+) (.*?) bag").unwrap();
+    let rules = input
+        .lines()
+        .map(|l| {
+            let color = re1
+                .captures(l)
+                .ok_or_else(|| ParseError::new(l, "expected `<color> bags contain ...`"))?
+                .get(1)
+                .unwrap()
+                .as_str();
+            let contents = re2
+                .captures_iter(l)
+                .map(|c| {
+                    Ok((
+                        value(c.get(1).unwrap().as_str())?,
+                        c.get(2).unwrap().as_str().to_owned(),
+                    ))
+                })
+                .collect::<Result<_, ParseError>>()?;
+            Ok((color.to_owned(), contents))
+        })
+        .collect::<Result<Rules, ParseError>>()?;
+
+    // every bag we're told to look inside needs its own rule
+    for l in input.lines() {
+        for c in re2.captures_iter(l).map(|c| c.get(2).unwrap()) {
+            if !rules.contains_key(c.as_str()) {
+                return Err(ParseError::new(c.as_str(), "no rule for this bag color"));
+            }
+        }
+    }
+
+    Ok(rules)
+}
+
+fn part1(Bags { graph, ids, .. }: &Bags, mine: &str) -> usize {
+    let holders = ids
+        .get(mine)
+        .map_or_else(Default::default, |&gold| graph.ancestors(gold));
+
+    let explain = Explain::new::<Day7>("finding holders");
+    if explain.is_on() {
+        let mut colors = holders.iter().map(|&id| graph.node(id)).collect::<Vec<_>>();
+        colors.sort_unstable();
+        for color in colors {
+            explain.note(|| format!("{} can hold {}", color, mine));
+        }
+    }
+    holders.len()
+}
+
+fn part2(Bags { graph, ids, order }: &Bags, mine: &str) -> usize {
+    // only the bags that end up inside ours are worth explaining
+    let explain = Explain::new::<Day7>("counting bags");
+    let counted = match (explain.is_on(), ids.get(mine)) {
+        (true, Some(&bag)) => graph
+            .descendants(bag)
+            .into_iter()
+            .chain(Some(bag))
+            .collect(),
+        _ => HashSet::new(),
+    };
+
+    // work back from the bags that hold nothing, so the bags inside each bag are already counted
+    let mut inside = vec![0; graph.len()];
+    for &bag in order.iter().rev() {
+        inside[bag.index()] = graph
+            .successors(bag)
+            .iter()
+            .map(|&(inner, amount)| amount * (1 + inside[inner.index()]))
+            .sum();
+        if counted.contains(&bag) {
+            explain.note(|| format!("{} holds {} bags", graph.node(bag), inside[bag.index()]));
+        }
+    }
+
+    ids.get(mine).map_or(0, |bag| inside[bag.index()])
+}
+
+#[cfg(test)]
+mod day7 {
+    use super::{parse_rules, Bag, Day7};
+    use aoc_common::parse_input;
+    use proptest::{collection::vec, prelude::*, sample::subsequence};
+
+    const COLORS: [&str; 12] = [
+        "shiny gold",
+        "dark red",
+        "light blue",
+        "faded plum",
+        "muted teal",
+        "vibrant olive",
+        "dotted black",
+        "pale cyan",
+        "wavy gold",
+        "posh lime",
+        "clear tan",
+        "drab coral",
+    ];
+
+    /// Rules where bags only ever contain bags later in the list, so none contain themselves.
+    fn rules() -> impl Strategy<Value = Vec<(String, Vec<Bag>)>> {
+        subsequence(COLORS[1..].to_vec(), 0..COLORS.len())
+            .prop_flat_map(|mut colors| {
+                colors.push(COLORS[0]);
+                Just(colors).prop_shuffle()
+            })
+            .prop_flat_map(|colors| {
+                let contents = (0..colors.len())
+                    .map(|idx| {
+                        let later = (idx + 1..colors.len()).collect::<Vec<_>>();
+                        let most = later.len().min(3);
+                        subsequence(later, 0..=most)
+                            .prop_flat_map(|inner| (vec(1..5_usize, inner.len()), Just(inner)))
+                    })
+                    .collect::<Vec<_>>();
+                (Just(colors), contents)
+            })
+            .prop_map(|(colors, contents)| {
+                let bag = |(amount, idx): (usize, usize)| (amount, colors[idx].to_owned());
+                colors
+                    .iter()
+                    .zip(contents)
+                    .map(|(color, (amounts, inner))| {
+                        let inner = amounts.into_iter().zip(inner).map(bag).collect();
+                        (color.to_string(), inner)
+                    })
+                    .collect()
+            })
+    }
+
+    fn print(rules: &[(String, Vec<Bag>)]) -> String {
+        let rule = |(color, contents): &(String, Vec<Bag>)| {
+            let contents = match contents.len() {
+                0 => String::from("no other bags"),
+                _ => contents
+                    .iter()
+                    .map(|(amount, inner)| match amount {
+                        1 => format!("1 {} bag", inner),
+                        _ => format!("{} {} bags", amount, inner),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            };
+            format!("{} bags contain {}.\n", color, contents)
+        };
+        rules.iter().map(rule).collect()
+    }
+
+    proptest! {
+        #[test]
+        fn parses_what_it_prints(rules in rules()) {
+            prop_assert_eq!(parse_rules(&print(&rules)).unwrap(), rules.into_iter().collect());
+        }
+    }
+
+    #[test]
+    fn should_blame_bags_that_contain_themselves() {
+        let input = "shiny gold bags contain 1 dark red bag.\n\
+                     dark red bags contain 2 shiny gold bags, 1 light blue bag.\n\
+                     light blue bags contain no other bags.\n";
+        let err = parse_input::<Day7>(input).err().unwrap();
+
+        assert_eq!(err.line(), 2);
+        assert!(err.reason().ends_with("dark red -> shiny gold -> dark red"));
+    }
+}
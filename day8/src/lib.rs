@@ -0,0 +1,228 @@
+use aoc_common::config::NoParams;
+use aoc_common::parse::value;
+use aoc_common::{ParseError, Solution, SolveError};
+use std::{collections::HashSet, str::FromStr};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Params = NoParams;
+    type Parsed = Vec<Instruction>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.lines().map(str::parse).collect()
+    }
+
+    fn part1(instructions: &Self::Parsed, _: &Self::Params) -> Result<i64, SolveError> {
+        Ok(pt1(instructions))
+    }
+
+    fn part2(instructions: &Self::Parsed, _: &Self::Params) -> Result<i64, SolveError> {
+        Ok(pt2(instructions))
+    }
+}
+
+fn pt1(instructions: &[Instruction]) -> i64 {
+    let mut c = Computer::new(instructions.to_vec());
+    c.run_to_end_or_loop().err().unwrap()
+}
+
+fn pt2(reference: &[Instruction]) -> i64 {
+    // make a reference program
+    reference
+        .iter()
+        .enumerate()
+        .filter(|(_, instr)| instr.opcode == OpCode::Jmp || instr.opcode == OpCode::Nop)
+        .map(|(idx, _)| (Computer::new(reference.to_vec()), idx))
+        .map(|(mut c, idx)| {
+            // flip nop and jmp at the idx
+            flip_at_idx(&mut c, idx);
+            c.run_to_end_or_loop()
+        })
+        .find_map(|r| r.ok())
+        .unwrap()
+}
+
+fn flip_at_idx(c: &mut Computer, idx: usize) {
+    let i = c.instruction_at(idx);
+    i.opcode = match i.opcode {
+        OpCode::Nop => OpCode::Jmp,
+        OpCode::Jmp => OpCode::Nop,
+        _ => unreachable!(), // should never be called with anything else due to the filter
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(i32)]
+pub enum OpCode {
+    Acc,
+    Jmp,
+    Nop,
+}
+
+impl FromStr for OpCode {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "acc" => Ok(OpCode::Acc),
+            "jmp" => Ok(OpCode::Jmp),
+            "nop" => Ok(OpCode::Nop),
+            _ => Err(ParseError::new(s, "expected `acc`, `jmp` or `nop`")),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Instruction {
+    pub opcode: OpCode,
+    pub argument: i64,
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let op = s.split_ascii_whitespace().collect::<Vec<&str>>();
+        if op.len() != 2 {
+            return Err(ParseError::new(s, "expected an operation and an argument"));
+        }
+        Ok(Instruction {
+            opcode: op[0].parse()?,
+            argument: value(op[1])?,
+        })
+    }
+}
+
+#[derive(Debug)]
+/// Runs a boot code program one instruction at a time.
+pub struct Computer {
+    pub ip: i64,
+    pub acc: i64,
+    pub instructions: Vec<Instruction>,
+}
+
+impl Computer {
+    pub fn new(instructions: Vec<Instruction>) -> Computer {
+        Computer {
+            ip: 0,
+            acc: 0,
+            instructions,
+        }
+    }
+
+    fn instruction_at(&mut self, idx: usize) -> &mut Instruction {
+        &mut self.instructions[idx]
+    }
+
+    fn current_instruction(&mut self) -> &Instruction {
+        self.instruction_at(self.ip as usize)
+    }
+
+    /// Executes the instruction at the instruction pointer.
+    pub fn step(&mut self) {
+        let curr = self.current_instruction();
+        match curr.opcode {
+            OpCode::Acc => {
+                self.acc += curr.argument;
+                self.ip += 1;
+            }
+            OpCode::Jmp => self.ip += curr.argument,
+            OpCode::Nop => self.ip += 1,
+        }
+    }
+
+    /// Runs until the program terminates, returning `Ok` with the accumulator, or until an
+    /// instruction is about to run a second time, returning `Err` with the accumulator.
+    pub fn run_to_end_or_loop(&mut self) -> Result<i64, i64> {
+        let mut seen_ips: HashSet<i64> = HashSet::new();
+        loop {
+            match (seen_ips.contains(&self.ip), (self.is_done())) {
+                (true, _) => return Err(self.acc),
+                (_, true) => return Ok(self.acc),
+                _ => {
+                    seen_ips.insert(self.ip);
+                    self.step();
+                }
+            }
+        }
+    }
+
+    /// Whether the instruction pointer is past the end of the program.
+    pub fn is_done(&mut self) -> bool {
+        self.ip >= self.instructions.len() as i64
+    }
+}
+
+#[cfg(test)]
+mod day8 {
+    use super::Day8;
+    use super::Instruction;
+    use super::OpCode;
+    use aoc_common::Solution;
+    use proptest::{collection::vec, prelude::*};
+
+    fn instruction() -> impl Strategy<Value = Instruction> {
+        let opcode = prop_oneof![Just(OpCode::Acc), Just(OpCode::Jmp), Just(OpCode::Nop)];
+        (opcode, -1000..1000_i64).prop_map(|(opcode, argument)| Instruction { opcode, argument })
+    }
+
+    fn print(program: &[Instruction]) -> String {
+        let mnemonic = |opcode| match opcode {
+            OpCode::Acc => "acc",
+            OpCode::Jmp => "jmp",
+            OpCode::Nop => "nop",
+        };
+        program
+            .iter()
+            .map(|i| format!("{} {:+}\n", mnemonic(i.opcode), i.argument))
+            .collect()
+    }
+
+    proptest! {
+        #[test]
+        fn parses_what_it_prints(program in vec(instruction(), 1..50)) {
+            let input = print(&program);
+            prop_assert_eq!(Day8::parse(&input).unwrap(), program.clone());
+            prop_assert_eq!(Day8::parse(input.trim_end()).unwrap(), program);
+        }
+    }
+
+    fn test_parse(str: &str, expected: Instruction) {
+        let res = str.parse::<Instruction>().unwrap();
+        assert_eq!(res, expected);
+    }
+
+    #[test]
+    fn should_parse_instructions() {
+        test_parse(
+            "nop +0",
+            Instruction {
+                opcode: OpCode::Nop,
+                argument: 0,
+            },
+        );
+        test_parse(
+            "acc +1",
+            Instruction {
+                opcode: OpCode::Acc,
+                argument: 1,
+            },
+        );
+        test_parse(
+            "jmp -1",
+            Instruction {
+                opcode: OpCode::Jmp,
+                argument: -1,
+            },
+        );
+    }
+
+    #[test]
+    fn should_reject_unknown_operations() {
+        let err = "mul +2".parse::<Instruction>().unwrap_err();
+        assert_eq!(err.text(), "mul");
+    }
+}
@@ -0,0 +1,169 @@
+use aoc_common::parse::numbers;
+use aoc_common::variants::{Variant, Variants};
+use aoc_common::{ParseError, Solution, SolveError};
+use itertools::Itertools;
+use serde::Deserialize;
+
+pub struct Day9;
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// How many numbers before each one it has to be the sum of two of.
+    pub preamble: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params { preamble: 25 }
+    }
+}
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Params = Params;
+    type Parsed = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        numbers(input)
+    }
+
+    fn part1(data: &Self::Parsed, params: &Params) -> Result<usize, SolveError> {
+        Ok(part1(data, params.preamble))
+    }
+
+    fn part2(data: &Self::Parsed, params: &Params) -> Result<usize, SolveError> {
+        Ok(part2(data, params.preamble))
+    }
+}
+
+impl Variants for Day9 {
+    fn part2_variants() -> Vec<Variant<Self, usize>> {
+        vec![Variant::new(
+            "every range",
+            |data: &Self::Parsed, params: &Params| Ok(part2_naive(data, params.preamble)),
+        )]
+    }
+}
+
+fn part1(data: &[usize], preamble_size: usize) -> usize {
+    *data
+        .iter()
+        .enumerate()
+        .skip(preamble_size)
+        .find(|(idx, _)| find_pair_for_item(data, *idx, preamble_size).is_none())
+        .map(|(_, number)| number)
+        .unwrap()
+}
+
+/// Slides a window along the numbers, growing it while it sums to less than the target and
+/// shrinking it from the front while it sums to more.
+fn part2(items: &[usize], preamble_size: usize) -> usize {
+    let target_number = part1(items, preamble_size);
+    let (mut start, mut end, mut sum) = (0, 0, 0);
+
+    while start < items.len() {
+        if sum == target_number && end - start >= 2 {
+            let range = &items[start..end];
+            return range.iter().min().unwrap() + range.iter().max().unwrap();
+        }
+        if end < items.len() && (sum < target_number || end - start < 2) {
+            sum += items[end];
+            end += 1;
+        } else {
+            sum -= items[start];
+            start += 1;
+        }
+    }
+
+    unreachable!();
+}
+
+/// Tries every range from every start, which takes quadratic time.
+fn part2_naive(items: &[usize], preamble_size: usize) -> usize {
+    let target_number = part1(items, preamble_size);
+    let get_range = |start, range_size| items.iter().skip(start).take(range_size).copied();
+
+    for start in 0..items.len() {
+        for range_size in 2..=items.len() - start {
+            let current_range = get_range(start, range_size);
+            if current_range.sum::<usize>() == target_number {
+                let range = get_range(start, range_size).collect::<Vec<usize>>();
+                return *range.iter().min().unwrap() + *range.iter().max().unwrap();
+            }
+        }
+    }
+
+    unreachable!();
+}
+
+/// Finds two different numbers among the `preamble_size` before `idx` that sum to the number
+/// at `idx`.
+pub fn find_pair_for_item(
+    items: &[usize],
+    idx: usize,
+    preamble_size: usize,
+) -> Option<Vec<&usize>> {
+    if idx < preamble_size {
+        return None;
+    }
+    items
+        .iter()
+        .skip(idx - preamble_size)
+        .take(preamble_size)
+        .combinations(2)
+        .find(|c| c.iter().map(|&&x| x).sum::<usize>() == *items.get(idx).unwrap())
+}
+
+#[cfg(test)]
+mod day9 {
+    use super::{find_pair_for_item, Day9, Params};
+    use aoc_common::{solve, variants::disagreements, Part};
+    use proptest::{collection::vec, prelude::*, sample::subsequence, sample::Index};
+
+    /// A stream where every number after the preamble of 25 is the sum of two of the 25 before
+    /// it, until the last, which is instead the sum of a contiguous run of earlier numbers.
+    fn stream() -> impl Strategy<Value = Vec<usize>> {
+        let preamble = subsequence((1..=60).collect::<Vec<usize>>(), 25).prop_shuffle();
+        let sums = vec((any::<Index>(), any::<Index>()), 0..15);
+
+        (preamble, sums, any::<Index>(), any::<Index>())
+            .prop_map(|(mut data, sums, start, len)| {
+                for (a, b) in sums {
+                    let window = &data[data.len() - 25..];
+                    let a = a.index(25);
+                    let b = (a + 1 + b.index(24)) % 25;
+                    data.push(window[a] + window[b]);
+                }
+                let start = start.index(data.len() - 1);
+                let len = 2 + len.index(data.len() - start - 1);
+                let weakness = data[start..start + len].iter().sum();
+                data.push(weakness);
+                data
+            })
+            .prop_filter("the last number needs to break the rule", |data| {
+                find_pair_for_item(data, data.len() - 1, 25).is_none()
+            })
+    }
+
+    fn print(data: &[usize]) -> String {
+        data.iter().map(|n| format!("{}\n", n)).collect()
+    }
+
+    proptest! {
+        #[test]
+        fn finds_the_number_breaking_the_rule(data in stream()) {
+            let invalid = data.last().unwrap().to_string();
+            prop_assert_eq!(solve::<Day9>(Part::One, &print(&data)), Ok(invalid));
+        }
+
+        #[test]
+        fn variants_agree(data in stream()) {
+            let found = disagreements::<Day9>(&Part::ALL, &print(&data), &Params::default());
+            prop_assert_eq!(found, Ok(vec![]));
+        }
+    }
+}